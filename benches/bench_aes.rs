@@ -8,7 +8,7 @@ use aes::{
     Aes128 as StdAes128,
 };
 use aes_gcm::KeyInit;
-use primit::symmetry::aes::Aes128;
+use primit::symmetry::aes::{Aes128, Aes256};
 use test::{black_box, Bencher};
 
 const DATA_LENGTH: usize = 1024 * 256;
@@ -40,6 +40,34 @@ fn bench_decrypt(b: &mut Bencher) {
         }
     });
 }
+#[bench]
+fn bench_encrypt_256(b: &mut Bencher) {
+    b.bytes = DATA_LENGTH as u64;
+
+    let mut d = black_box([0u8; DATA_LENGTH]);
+    let cipher = Aes256::new(&[0u8; 32]);
+
+    b.iter(|| {
+        for chunk in d.as_chunks_mut::<16>().0 {
+            cipher.encrypt(chunk)
+        }
+    });
+}
+
+#[bench]
+fn bench_decrypt_256(b: &mut Bencher) {
+    b.bytes = DATA_LENGTH as u64;
+
+    let mut d = black_box([0u8; DATA_LENGTH]);
+    let cipher = Aes256::new(&[0u8; 32]);
+
+    b.iter(|| {
+        for chunk in d.as_chunks_mut::<16>().0 {
+            cipher.decrypt(chunk)
+        }
+    });
+}
+
 #[bench]
 fn bench_std_encrypt(b: &mut Bencher) {
     b.bytes = DATA_LENGTH as u64;
@@ -5,7 +5,10 @@ extern crate test;
 
 use aes::cipher::generic_array::GenericArray;
 use aes_gcm::{AeadInPlace, Aes128Gcm, KeyInit, Tag};
-use primit::aead::{aesgcm::AESGCM, Aead, Decryptor, Encryptor};
+use primit::aead::{
+    aesgcm::{AES256GCM, AESGCM},
+    Aead, Decryptor, Encryptor,
+};
 use test::{black_box, Bencher};
 
 const DATA_LENGTH: usize = 1024 * 256;
@@ -35,6 +38,18 @@ fn bench_aesgcm_decrypt(b: &mut Bencher) {
             .ok();
     });
 }
+#[bench]
+fn bench_aes256gcm_encrypt(b: &mut Bencher) {
+    b.bytes = DATA_LENGTH as u64;
+
+    let alg = black_box(AES256GCM::new(&[0u8; 32]));
+    let mut d = black_box([0u8; DATA_LENGTH]);
+
+    b.iter(|| {
+        alg.encryptor(&[0u8; 12], &[]).finalize(&mut d);
+    });
+}
+
 #[bench]
 fn bench_std_aesgcm_encrypt(b: &mut Bencher) {
     b.bytes = DATA_LENGTH as u64;
@@ -0,0 +1,52 @@
+#![feature(test)]
+
+extern crate test;
+
+use primit::aead::aesgcmsiv::{AES256GCMSIV, AESGCMSIV};
+use test::{black_box, Bencher};
+
+const DATA_LENGTH: usize = 1024 * 256;
+
+#[bench]
+fn bench_aesgcmsiv_encrypt(b: &mut Bencher) {
+    b.bytes = DATA_LENGTH as u64;
+
+    let alg = black_box(AESGCMSIV::new(&[0u8; 16]));
+    let mut d = black_box([0u8; DATA_LENGTH]);
+
+    b.iter(|| {
+        alg.encrypt(black_box(&[0u8; 12]), black_box(&[]), &mut d)
+            .ok();
+    });
+}
+
+#[bench]
+fn bench_aesgcmsiv_decrypt(b: &mut Bencher) {
+    b.bytes = DATA_LENGTH as u64;
+
+    let alg = black_box(AESGCMSIV::new(&[0u8; 16]));
+    let mut d = black_box([0u8; DATA_LENGTH]);
+
+    b.iter(|| {
+        alg.decrypt(
+            black_box(&[0u8; 12]),
+            black_box(&[]),
+            &mut d,
+            black_box(&[0u8; 16]),
+        )
+        .ok();
+    });
+}
+
+#[bench]
+fn bench_aes256gcmsiv_encrypt(b: &mut Bencher) {
+    b.bytes = DATA_LENGTH as u64;
+
+    let alg = black_box(AES256GCMSIV::new(&[0u8; 32]));
+    let mut d = black_box([0u8; DATA_LENGTH]);
+
+    b.iter(|| {
+        alg.encrypt(black_box(&[0u8; 12]), black_box(&[]), &mut d)
+            .ok();
+    });
+}
@@ -0,0 +1,33 @@
+#![feature(test)]
+
+extern crate test;
+
+use primit::aead::aessiv::AESSIV;
+use test::{black_box, Bencher};
+
+const DATA_LENGTH: usize = 1024 * 256;
+
+#[bench]
+fn bench_aessiv_encrypt(b: &mut Bencher) {
+    b.bytes = DATA_LENGTH as u64;
+
+    let alg = black_box(AESSIV::new(&[0u8; 32]));
+    let mut d = black_box([0u8; DATA_LENGTH]);
+
+    b.iter(|| {
+        alg.encrypt(black_box(&[&[0u8; 16]]), &mut d);
+    });
+}
+
+#[bench]
+fn bench_aessiv_decrypt(b: &mut Bencher) {
+    b.bytes = DATA_LENGTH as u64;
+
+    let alg = black_box(AESSIV::new(&[0u8; 32]));
+    let mut d = black_box([0u8; DATA_LENGTH]);
+
+    b.iter(|| {
+        alg.decrypt(black_box(&[&[0u8; 16]]), &mut d, black_box(&[0u8; 16]))
+            .ok();
+    });
+}
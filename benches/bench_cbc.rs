@@ -0,0 +1,28 @@
+#![feature(test)]
+
+extern crate test;
+
+use primit::symmetry::{aes::Aes128, mode::cbc};
+use test::{black_box, Bencher};
+
+const DATA_LENGTH: usize = 1024 * 256;
+
+#[bench]
+fn bench_cbc_encrypt(b: &mut Bencher) {
+    b.bytes = DATA_LENGTH as u64;
+
+    let mut d = black_box([0u8; DATA_LENGTH]);
+    let cipher = Aes128::new(&[0u8; 16]);
+
+    b.iter(|| cbc::encrypt(&cipher, &[0u8; 16], &mut d));
+}
+
+#[bench]
+fn bench_cbc_decrypt(b: &mut Bencher) {
+    b.bytes = DATA_LENGTH as u64;
+
+    let mut d = black_box([0u8; DATA_LENGTH]);
+    let cipher = Aes128::new(&[0u8; 16]);
+
+    b.iter(|| cbc::decrypt(&cipher, &[0u8; 16], &mut d));
+}
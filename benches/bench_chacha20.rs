@@ -3,7 +3,7 @@
 
 extern crate test;
 
-use primit::symmetry::chacha::ChaCha20;
+use primit::symmetry::chacha::{ChaCha12, ChaCha20, ChaCha8};
 use test::{black_box, Bencher};
 
 const DATA_LENGTH: usize = 1024 * 256;
@@ -17,3 +17,23 @@ fn bench_chacha20(b: &mut Bencher) {
 
     b.iter(|| cipher.apply(&mut d));
 }
+
+#[bench]
+fn bench_chacha12(b: &mut Bencher) {
+    b.bytes = DATA_LENGTH as u64;
+
+    let mut cipher = ChaCha12::new(&[0u8; 32], &[0u8; 12]);
+    let mut d = black_box([0u8; DATA_LENGTH]);
+
+    b.iter(|| cipher.apply(&mut d));
+}
+
+#[bench]
+fn bench_chacha8(b: &mut Bencher) {
+    b.bytes = DATA_LENGTH as u64;
+
+    let mut cipher = ChaCha8::new(&[0u8; 32], &[0u8; 12]);
+    let mut d = black_box([0u8; DATA_LENGTH]);
+
+    b.iter(|| cipher.apply(&mut d));
+}
@@ -0,0 +1,18 @@
+#![feature(test)]
+
+extern crate test;
+
+use primit::{mac::cmac::cmac, symmetry::aes::Aes128};
+use test::{black_box, Bencher};
+
+const DATA_LENGTH: usize = 1024 * 256;
+
+#[bench]
+fn bench_cmac(b: &mut Bencher) {
+    b.bytes = DATA_LENGTH as u64;
+
+    let cipher = Aes128::new(&[233u8; 16]);
+    let d = black_box([233u8; DATA_LENGTH]);
+
+    b.iter(|| cmac(cipher, &d));
+}
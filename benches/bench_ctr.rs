@@ -0,0 +1,31 @@
+#![feature(test)]
+
+extern crate test;
+
+use aes::cipher::{KeyIvInit, StreamCipher};
+use primit::symmetry::{aes::Aes128, mode::ctr::Ctr};
+use test::{black_box, Bencher};
+
+const DATA_LENGTH: usize = 1024 * 256;
+
+#[bench]
+fn bench_ctr(b: &mut Bencher) {
+    b.bytes = DATA_LENGTH as u64;
+
+    let mut d = black_box([0u8; DATA_LENGTH]);
+    let cipher = Aes128::new(&[0u8; 16]);
+
+    b.iter(|| Ctr::new(cipher, &[0u8; 16]).apply(&mut d));
+}
+
+#[bench]
+fn bench_std_ctr(b: &mut Bencher) {
+    b.bytes = DATA_LENGTH as u64;
+
+    let mut d = black_box([0u8; DATA_LENGTH]);
+
+    b.iter(|| {
+        ctr::Ctr128BE::<aes::Aes128>::new(&[0u8; 16].into(), &[0u8; 16].into())
+            .apply_keystream(&mut d)
+    });
+}
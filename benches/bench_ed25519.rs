@@ -0,0 +1,24 @@
+#![feature(test)]
+
+extern crate test;
+
+use primit::ec::{ed25519::Ed25519, DSA};
+use test::{black_box, Bencher};
+
+#[bench]
+fn bench_ed25519_sign(b: &mut Bencher) {
+    let sk = black_box(Ed25519::new(&[16u8; 32]));
+    let msg = black_box([0u8; 64]);
+
+    b.iter(|| sk.sign(&msg));
+}
+
+#[bench]
+fn bench_ed25519_verify(b: &mut Bencher) {
+    let sk = Ed25519::new(&[16u8; 32]);
+    let pk = black_box(sk.to_public());
+    let msg = black_box([0u8; 64]);
+    let sig = black_box(sk.sign(&msg));
+
+    b.iter(|| Ed25519::verify(&pk, &msg, &sig));
+}
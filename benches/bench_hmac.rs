@@ -2,7 +2,13 @@
 
 extern crate test;
 
-use primit::{hash::sha256::SHA256, mac::hmac::hmac};
+use primit::{
+    hash::sha256::SHA256,
+    mac::{
+        hmac::{hmac, Hmac},
+        Mac,
+    },
+};
 use test::{black_box, Bencher};
 
 const DATA_LENGTH: usize = 1024 * 256;
@@ -26,3 +32,13 @@ fn bench_hmac_sha256_small(b: &mut Bencher) {
 
     b.iter(|| hmac::<SHA256>(&k, &d));
 }
+
+#[bench]
+fn bench_hmac_sha256_small_keyed(b: &mut Bencher) {
+    b.bytes = 32;
+
+    let h = black_box(Hmac::<SHA256>::new(&[233u8; 32]));
+    let d = black_box([233u8; 32]);
+
+    b.iter(|| h.clone().finalize(&d));
+}
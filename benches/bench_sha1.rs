@@ -0,0 +1,27 @@
+#![feature(test)]
+#![feature(slice_as_chunks)]
+
+extern crate test;
+
+use primit::hash::sha1::{sha1, sha1_checked};
+use test::{black_box, Bencher};
+
+const DATA_LENGTH: usize = 1024 * 256;
+
+#[bench]
+fn bench_sha1(b: &mut Bencher) {
+    b.bytes = DATA_LENGTH as u64;
+
+    let data = black_box([0u8; DATA_LENGTH]);
+
+    b.iter(|| sha1(&data));
+}
+
+#[bench]
+fn bench_sha1_checked(b: &mut Bencher) {
+    b.bytes = DATA_LENGTH as u64;
+
+    let data = black_box([0u8; DATA_LENGTH]);
+
+    b.iter(|| sha1_checked(&data));
+}
@@ -0,0 +1,18 @@
+#![feature(test)]
+#![feature(slice_as_chunks)]
+
+extern crate test;
+
+use primit::hash::sha384::sha384;
+use test::{black_box, Bencher};
+
+const DATA_LENGTH: usize = 1024 * 256;
+
+#[bench]
+fn bench_sha384(b: &mut Bencher) {
+    b.bytes = DATA_LENGTH as u64;
+
+    let data = black_box([0u8; DATA_LENGTH]);
+
+    b.iter(|| sha384(&data));
+}
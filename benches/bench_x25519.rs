@@ -0,0 +1,14 @@
+#![feature(test)]
+
+extern crate test;
+
+use primit::ec::{x25519::X25519, ECDH};
+use test::{black_box, Bencher};
+
+#[bench]
+fn bench_x25519(b: &mut Bencher) {
+    let sk = black_box(X25519::new(&[16u8; 32]));
+    let pk = sk.to_public();
+
+    b.iter(|| sk.exchange(&pk));
+}
@@ -0,0 +1,28 @@
+#![feature(test)]
+
+extern crate test;
+
+use primit::symmetry::{aes::Aes128, mode::xts::Xts};
+use test::{black_box, Bencher};
+
+const SECTOR_LENGTH: usize = 4096;
+
+#[bench]
+fn bench_xts_encrypt(b: &mut Bencher) {
+    b.bytes = SECTOR_LENGTH as u64;
+
+    let mut d = black_box([0u8; SECTOR_LENGTH]);
+    let xts = Xts::new(Aes128::new(&[0u8; 16]), Aes128::new(&[1u8; 16]));
+
+    b.iter(|| xts.encrypt_sector(black_box(1), &mut d));
+}
+
+#[bench]
+fn bench_xts_decrypt(b: &mut Bencher) {
+    b.bytes = SECTOR_LENGTH as u64;
+
+    let mut d = black_box([0u8; SECTOR_LENGTH]);
+    let xts = Xts::new(Aes128::new(&[0u8; 16]), Aes128::new(&[1u8; 16]));
+
+    b.iter(|| xts.decrypt_sector(black_box(1), &mut d));
+}
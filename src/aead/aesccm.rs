@@ -0,0 +1,186 @@
+// RFC3610 https://tools.ietf.org/html/rfc3610
+// NIST SP800-38C https://nvlpubs.nist.gov/nistpubs/Legacy/SP/nistspecialpublication800-38c.pdf
+
+use crate::{
+    error::AeadError,
+    symmetry::{
+        aes::Aes128,
+        mode::ctr::{CounterEndian, Ctr},
+        BlockCipher,
+    },
+    utils::{ct::ct_eq, xor::xor},
+};
+
+// `TAG_LENGTH` is one of 4, 6, 8, 10, 12, 14, 16 and `NONCE_LENGTH` is from 7 to 13,
+// checked at compile time
+#[derive(Debug, Clone)]
+pub struct Ccm<C, const TAG_LENGTH: usize, const NONCE_LENGTH: usize>(C);
+
+pub type AESCCM<const TAG_LENGTH: usize, const NONCE_LENGTH: usize> =
+    Ccm<Aes128, TAG_LENGTH, NONCE_LENGTH>;
+
+impl<C: BlockCipher<16> + Clone, const TAG_LENGTH: usize, const NONCE_LENGTH: usize>
+    Ccm<C, TAG_LENGTH, NONCE_LENGTH>
+where
+    [u8; C::KEY_LENGTH]: Sized,
+{
+    // the length field of the message takes the rest of the block
+    const LENGTH_SIZE: usize = 15 - NONCE_LENGTH;
+
+    const VALID_PARAMETERS: () = assert!(
+        TAG_LENGTH >= 4
+            && TAG_LENGTH <= 16
+            && TAG_LENGTH.is_multiple_of(2)
+            && NONCE_LENGTH >= 7
+            && NONCE_LENGTH <= 13
+    );
+
+    pub fn new(key: &[u8; C::KEY_LENGTH]) -> Self {
+        Self::from_cipher(C::new(key))
+    }
+
+    pub fn from_cipher(cipher: C) -> Self {
+        let () = Self::VALID_PARAMETERS;
+        Self(cipher)
+    }
+
+    // encrypt `data` in place, return the tag
+    //
+    // `InvalidLength` if the message length doesn't fit in `15 - NONCE_LENGTH` bytes
+    pub fn encrypt(
+        &self,
+        nonce: &[u8; NONCE_LENGTH],
+        ad: &[u8],
+        data: &mut [u8],
+    ) -> Result<[u8; TAG_LENGTH], AeadError> {
+        Self::check_length(data)?;
+
+        let mac = self.cbc_mac(nonce, ad, data);
+
+        let mut s0 = [0u8; 16];
+        let mut ctr = self.ctr(nonce);
+        ctr.apply(&mut s0);
+        ctr.apply(data);
+
+        let mut tag = [0u8; TAG_LENGTH];
+        tag.copy_from_slice(&mac[..TAG_LENGTH]);
+        xor(&mut tag, &s0);
+        Ok(tag)
+    }
+
+    // decrypt `data` in place, it is zeroed if the tag doesn't match
+    // and left untouched if its length is invalid
+    pub fn decrypt(
+        &self,
+        nonce: &[u8; NONCE_LENGTH],
+        ad: &[u8],
+        data: &mut [u8],
+        mac: &[u8; TAG_LENGTH],
+    ) -> Result<(), AeadError> {
+        Self::check_length(data)?;
+
+        let mut s0 = [0u8; 16];
+        let mut ctr = self.ctr(nonce);
+        ctr.apply(&mut s0);
+        ctr.apply(data);
+
+        let mut expected = self.cbc_mac(nonce, ad, data);
+        xor(&mut expected, &s0);
+        if !ct_eq(&expected[..TAG_LENGTH], mac) {
+            data.fill(0);
+            return Err(AeadError::BadMac);
+        }
+        Ok(())
+    }
+
+    // the message length must fit in the length field, or the counter would wrap around
+    fn check_length(data: &[u8]) -> Result<(), AeadError> {
+        let length_size = Self::LENGTH_SIZE;
+        if length_size < 8 && (data.len() as u64) >> (8 * length_size) != 0 {
+            return Err(AeadError::InvalidLength);
+        }
+        Ok(())
+    }
+
+    // RFC3610 2.2
+    fn cbc_mac(&self, nonce: &[u8; NONCE_LENGTH], ad: &[u8], data: &[u8]) -> [u8; 16] {
+        let length_size = Self::LENGTH_SIZE;
+
+        let mut b0 = [0u8; 16];
+        b0[0] = (u8::from(!ad.is_empty()) << 6)
+            | (((TAG_LENGTH - 2) / 2) as u8) << 3
+            | (length_size - 1) as u8;
+        b0[1..1 + NONCE_LENGTH].copy_from_slice(nonce);
+        b0[1 + NONCE_LENGTH..]
+            .copy_from_slice(&(data.len() as u64).to_be_bytes()[8 - length_size..]);
+
+        let mut mac = CbcMac::new(&self.0, &b0);
+
+        if !ad.is_empty() {
+            let ad_length = ad.len() as u64;
+            if ad_length < 0xff00 {
+                mac.update(&(ad_length as u16).to_be_bytes());
+            } else if ad_length <= u32::MAX as u64 {
+                mac.update(&[0xff, 0xfe]);
+                mac.update(&(ad_length as u32).to_be_bytes());
+            } else {
+                mac.update(&[0xff, 0xff]);
+                mac.update(&ad_length.to_be_bytes());
+            }
+            mac.update(ad);
+            mac.pad();
+        }
+
+        mac.update(data);
+        mac.pad();
+        mac.state
+    }
+
+    // RFC3610 2.3, the first block of keystream encrypts the tag
+    fn ctr(&self, nonce: &[u8; NONCE_LENGTH]) -> Ctr<C> {
+        let mut a0 = [0u8; 16];
+        a0[0] = (Self::LENGTH_SIZE - 1) as u8;
+        a0[1..1 + NONCE_LENGTH].copy_from_slice(nonce);
+        Ctr::new_with_counter(self.0.clone(), &a0, Self::LENGTH_SIZE, CounterEndian::Big)
+    }
+}
+
+// the input is zero padded to a block boundary by `pad`
+struct CbcMac<'a, C> {
+    cipher: &'a C,
+    state: [u8; 16],
+    offset: usize,
+}
+
+impl<'a, C: BlockCipher<16>> CbcMac<'a, C> {
+    fn new(cipher: &'a C, b0: &[u8; 16]) -> Self {
+        let mut state = *b0;
+        cipher.encrypt(&mut state);
+        Self {
+            cipher,
+            state,
+            offset: 0,
+        }
+    }
+
+    fn update(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            let n = data.len().min(16 - self.offset);
+            xor(&mut self.state[self.offset..self.offset + n], &data[..n]);
+            self.offset += n;
+            data = &data[n..];
+
+            if self.offset == 16 {
+                self.cipher.encrypt(&mut self.state);
+                self.offset = 0;
+            }
+        }
+    }
+
+    fn pad(&mut self) {
+        if self.offset != 0 {
+            self.cipher.encrypt(&mut self.state);
+            self.offset = 0;
+        }
+    }
+}
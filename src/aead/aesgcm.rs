@@ -2,52 +2,51 @@ use super::{Aead, Decryptor, Encryptor};
 use crate::{
     error::AeadError,
     mac::{ghash::GHash, Mac},
-    symmetry::aes::Aes128,
+    symmetry::{
+        aes::{Aes128, Aes256},
+        BlockCipher,
+    },
     utils::xor::{xor, xor_static},
 };
 
-const KEY_LENGTH: usize = 16;
 const BLOCK_LENGTH: usize = 16;
 const NONCE_LENGTH: usize = 12;
 const MAC_LENGTH: usize = 16;
 
-fn next_key(encryptor: &mut AESGCMEncryptor) -> [u8; 16] {
-    let mut key = encryptor.state;
-
-    // increase counter
-    let counter_slice = &mut encryptor.state[12..16];
-    let mut count = u32::from_be_bytes(counter_slice.try_into().unwrap());
-    count = count.wrapping_add(1);
-    counter_slice.copy_from_slice(&count.to_be_bytes());
-
-    encryptor.cipher.encrypt(&mut key);
-    key
-}
-
-pub struct AESGCMEncryptor {
-    cipher: Aes128,
+pub struct GcmEncryptor<C> {
+    cipher: C,
     state: [u8; 16],
     mac: GHash,
     ad_length: usize,
     data_length: usize,
 }
 
-impl Encryptor for AESGCMEncryptor {
-    const BLOCK_LENGTH: usize = BLOCK_LENGTH;
-    const MAC_LENGTH: usize = MAC_LENGTH;
+impl<C: BlockCipher<16>> GcmEncryptor<C> {
+    fn next_key(&mut self) -> [u8; 16] {
+        let mut key = self.state;
+
+        // increase counter
+        let counter_slice = &mut self.state[12..16];
+        let mut count = u32::from_be_bytes(counter_slice.try_into().unwrap());
+        count = count.wrapping_add(1);
+        counter_slice.copy_from_slice(&count.to_be_bytes());
+
+        self.cipher.encrypt(&mut key);
+        key
+    }
 
-    fn encrypt(&mut self, data: &mut [u8; Self::BLOCK_LENGTH]) {
+    fn encrypt_block(&mut self, data: &mut [u8; BLOCK_LENGTH]) {
         self.data_length += data.len();
-        xor_static(data, &next_key(self));
+        xor_static(data, &self.next_key());
         self.mac.update(data);
     }
 
-    fn finalize(mut self, remainder: &mut [u8]) -> [u8; Self::MAC_LENGTH] {
+    fn encrypt_finalize(mut self, remainder: &mut [u8]) -> [u8; MAC_LENGTH] {
         self.data_length += remainder.len();
         let (blocks, remainder) = remainder.as_chunks_mut();
 
         for block in blocks.iter_mut() {
-            xor_static(block, &next_key(&mut self));
+            xor_static(block, &self.next_key());
         }
 
         for block in blocks.iter_mut() {
@@ -55,7 +54,7 @@ impl Encryptor for AESGCMEncryptor {
         }
 
         if !remainder.is_empty() {
-            xor(remainder, &next_key(&mut self));
+            xor(remainder, &self.next_key());
             let mut buffer = [0u8; 16];
             buffer[..remainder.len()].copy_from_slice(remainder);
             self.mac.update(&buffer);
@@ -77,22 +76,19 @@ impl Encryptor for AESGCMEncryptor {
     }
 }
 
-pub struct AESGCMDecryptor(AESGCMEncryptor);
+pub struct GcmDecryptor<C>(GcmEncryptor<C>);
 
-impl Decryptor for AESGCMDecryptor {
-    const BLOCK_LENGTH: usize = BLOCK_LENGTH;
-    const MAC_LENGTH: usize = MAC_LENGTH;
-
-    fn decrypt(&mut self, data: &mut [u8; Self::BLOCK_LENGTH]) {
+impl<C: BlockCipher<16>> GcmDecryptor<C> {
+    fn decrypt_block(&mut self, data: &mut [u8; BLOCK_LENGTH]) {
         self.0.data_length += data.len();
         self.0.mac.update(data);
-        xor_static(data, &next_key(&mut self.0));
+        xor_static(data, &self.0.next_key());
     }
 
-    fn finalize(
+    fn decrypt_finalize(
         mut self,
         remainder: &mut [u8],
-        mac: &[u8; Self::MAC_LENGTH],
+        mac: &[u8; MAC_LENGTH],
     ) -> Result<(), AeadError> {
         self.0.data_length += remainder.len();
 
@@ -101,14 +97,14 @@ impl Decryptor for AESGCMDecryptor {
             self.0.mac.update(block);
         }
         for block in blocks {
-            xor_static(block, &next_key(&mut self.0));
+            xor_static(block, &self.0.next_key());
         }
 
         if !remainder.is_empty() {
             let mut buffer = [0u8; 16];
             buffer[..remainder.len()].copy_from_slice(remainder);
             self.0.mac.update(&buffer);
-            xor(remainder, &next_key(&mut self.0));
+            xor(remainder, &self.0.next_key());
         }
 
         let mut len_block = [0u8; 16];
@@ -131,20 +127,10 @@ impl Decryptor for AESGCMDecryptor {
     }
 }
 
-pub struct AESGCM(Aes128);
-
-impl Aead for AESGCM {
-    const KEY_LENGTH: usize = KEY_LENGTH;
-    const NONCE_LENGTH: usize = NONCE_LENGTH;
-
-    type Encryptor = AESGCMEncryptor;
-    type Decryptor = AESGCMDecryptor;
+pub struct Gcm<C>(C);
 
-    fn new(key: &[u8; Self::KEY_LENGTH]) -> Self {
-        Self(Aes128::new(key))
-    }
-
-    fn encryptor(&self, nonce: &[u8; Self::NONCE_LENGTH], ad: &[u8]) -> Self::Encryptor {
+impl<C: BlockCipher<16> + Clone> Gcm<C> {
+    fn new_encryptor(&self, nonce: &[u8; NONCE_LENGTH], ad: &[u8]) -> GcmEncryptor<C> {
         let mut ghash_key = [0u8; 16];
         self.0.encrypt(&mut ghash_key);
 
@@ -166,17 +152,78 @@ impl Aead for AESGCM {
 
         state[15] = 2;
 
-        AESGCMEncryptor {
-            cipher: self.0,
+        GcmEncryptor {
+            cipher: self.0.clone(),
             state,
             mac,
             ad_length: ad.len(),
             data_length: 0,
         }
     }
+}
 
-    fn decryptor(&self, nonce: &[u8; Self::NONCE_LENGTH], ad: &[u8]) -> Self::Decryptor {
-        let encryptor = self.encryptor(nonce, ad);
-        AESGCMDecryptor(encryptor)
-    }
+// `Self::BLOCK_LENGTH` is not unified with `16` in a generic impl under generic_const_exprs,
+// so the traits are implemented for every cipher
+macro_rules! impl_gcm {
+    ($($cipher:ty),*) => {$(
+        impl Encryptor for GcmEncryptor<$cipher> {
+            const BLOCK_LENGTH: usize = BLOCK_LENGTH;
+            const MAC_LENGTH: usize = MAC_LENGTH;
+
+            fn encrypt(&mut self, data: &mut [u8; Self::BLOCK_LENGTH]) {
+                self.encrypt_block(data);
+            }
+
+            fn finalize(self, remainder: &mut [u8]) -> [u8; Self::MAC_LENGTH] {
+                self.encrypt_finalize(remainder)
+            }
+        }
+
+        impl Decryptor for GcmDecryptor<$cipher> {
+            const BLOCK_LENGTH: usize = BLOCK_LENGTH;
+            const MAC_LENGTH: usize = MAC_LENGTH;
+
+            fn decrypt(&mut self, data: &mut [u8; Self::BLOCK_LENGTH]) {
+                self.decrypt_block(data);
+            }
+
+            fn finalize(
+                self,
+                remainder: &mut [u8],
+                mac: &[u8; Self::MAC_LENGTH],
+            ) -> Result<(), AeadError> {
+                self.decrypt_finalize(remainder, mac)
+            }
+        }
+
+        impl Aead for Gcm<$cipher> {
+            const KEY_LENGTH: usize = <$cipher as BlockCipher<16>>::KEY_LENGTH;
+            const NONCE_LENGTH: usize = NONCE_LENGTH;
+
+            type Encryptor = GcmEncryptor<$cipher>;
+            type Decryptor = GcmDecryptor<$cipher>;
+
+            fn new(key: &[u8; Self::KEY_LENGTH]) -> Self {
+                Self(<$cipher>::new(key))
+            }
+
+            fn encryptor(&self, nonce: &[u8; Self::NONCE_LENGTH], ad: &[u8]) -> Self::Encryptor {
+                self.new_encryptor(nonce, ad)
+            }
+
+            fn decryptor(&self, nonce: &[u8; Self::NONCE_LENGTH], ad: &[u8]) -> Self::Decryptor {
+                GcmDecryptor(self.new_encryptor(nonce, ad))
+            }
+        }
+    )*};
 }
+
+impl_gcm!(Aes128, Aes256);
+
+pub type AESGCM = Gcm<Aes128>;
+pub type AESGCMEncryptor = GcmEncryptor<Aes128>;
+pub type AESGCMDecryptor = GcmDecryptor<Aes128>;
+
+pub type AES256GCM = Gcm<Aes256>;
+pub type AES256GCMEncryptor = GcmEncryptor<Aes256>;
+pub type AES256GCMDecryptor = GcmDecryptor<Aes256>;
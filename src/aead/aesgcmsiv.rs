@@ -0,0 +1,135 @@
+// RFC8452 https://tools.ietf.org/html/rfc8452
+
+use crate::{
+    error::AeadError,
+    mac::{polyval::Polyval, Mac},
+    symmetry::{
+        aes::{Aes128, Aes256},
+        mode::ctr::{CounterEndian, Ctr},
+        BlockCipher,
+    },
+    utils::{ct::ct_eq, xor::xor},
+};
+
+// `C` is the key-generating cipher, and also the message-encryption one
+#[derive(Debug, Clone)]
+pub struct GcmSiv<C>(C);
+
+pub type AESGCMSIV = GcmSiv<Aes128>;
+pub type AES256GCMSIV = GcmSiv<Aes256>;
+
+impl<C: BlockCipher<16> + Clone> GcmSiv<C>
+where
+    [u8; C::KEY_LENGTH]: Sized,
+{
+    pub fn new(key: &[u8; C::KEY_LENGTH]) -> Self {
+        Self(C::new(key))
+    }
+
+    // encrypt `data` in place, return the tag
+    //
+    // `InvalidLength` if `ad` or `data` is longer than 2^36 bytes
+    pub fn encrypt(
+        &self,
+        nonce: &[u8; 12],
+        ad: &[u8],
+        data: &mut [u8],
+    ) -> Result<[u8; 16], AeadError> {
+        check_lengths(ad, data)?;
+
+        let (auth_key, cipher) = self.derive_keys(nonce);
+        let tag = tag(&cipher, &auth_key, nonce, ad, data);
+        apply_keystream(cipher, &tag, data);
+        Ok(tag)
+    }
+
+    // decrypt `data` in place, it is zeroed if the tag doesn't match
+    pub fn decrypt(
+        &self,
+        nonce: &[u8; 12],
+        ad: &[u8],
+        data: &mut [u8],
+        mac: &[u8; 16],
+    ) -> Result<(), AeadError> {
+        check_lengths(ad, data)?;
+
+        let (auth_key, cipher) = self.derive_keys(nonce);
+        apply_keystream(cipher.clone(), mac, data);
+
+        let expected = tag(&cipher, &auth_key, nonce, ad, data);
+        if !ct_eq(&expected, mac) {
+            data.fill(0);
+            return Err(AeadError::BadMac);
+        }
+        Ok(())
+    }
+
+    // RFC8452 4, per-nonce authentication and encryption keys
+    fn derive_keys(&self, nonce: &[u8; 12]) -> ([u8; 16], C) {
+        let mut material = [0u8; 48];
+        for (i, chunk) in material[..16 + C::KEY_LENGTH]
+            .as_chunks_mut::<8>()
+            .0
+            .iter_mut()
+            .enumerate()
+        {
+            let mut block = [0u8; 16];
+            block[..4].copy_from_slice(&(i as u32).to_le_bytes());
+            block[4..].copy_from_slice(nonce);
+            self.0.encrypt(&mut block);
+            chunk.copy_from_slice(&block[..8]);
+        }
+
+        let auth_key = material[..16].try_into().unwrap();
+        let cipher = C::new(material[16..16 + C::KEY_LENGTH].try_into().unwrap());
+        (auth_key, cipher)
+    }
+}
+
+// RFC8452 6, the 32-bit block counter would wrap around past 2^36 bytes
+fn check_lengths(ad: &[u8], data: &[u8]) -> Result<(), AeadError> {
+    const MAX_LENGTH: u64 = 1 << 36;
+    if ad.len() as u64 > MAX_LENGTH || data.len() as u64 > MAX_LENGTH {
+        return Err(AeadError::InvalidLength);
+    }
+    Ok(())
+}
+
+fn tag<C: BlockCipher<16>>(
+    cipher: &C,
+    auth_key: &[u8; 16],
+    nonce: &[u8; 12],
+    ad: &[u8],
+    data: &[u8],
+) -> [u8; 16] {
+    let mut polyval = Polyval::new(auth_key);
+    for input in [ad, data] {
+        let (blocks, remainder) = input.as_chunks();
+        for block in blocks {
+            polyval.update(block);
+        }
+        if !remainder.is_empty() {
+            let mut buffer = [0u8; 16];
+            buffer[..remainder.len()].copy_from_slice(remainder);
+            polyval.update(&buffer);
+        }
+    }
+
+    let mut length_block = [0u8; 16];
+    length_block[..8].copy_from_slice(&(ad.len() as u64 * 8).to_le_bytes());
+    length_block[8..].copy_from_slice(&(data.len() as u64 * 8).to_le_bytes());
+    let mut s = polyval.finalize(&length_block);
+
+    xor(&mut s[..12], nonce);
+    s[15] &= 0x7f;
+    cipher.encrypt(&mut s);
+    s
+}
+
+// the tag with the top bit set is the initial counter block, the counter is
+// the first 32 bits in little-endian
+fn apply_keystream<C: BlockCipher<16>>(cipher: C, tag: &[u8; 16], data: &mut [u8]) {
+    let mut iv = *tag;
+    iv[15] |= 0x80;
+    Ctr::new_with_counter(cipher, &iv, 4, CounterEndian::Little).apply(data);
+}
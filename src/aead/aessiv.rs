@@ -0,0 +1,118 @@
+// RFC5297 https://tools.ietf.org/html/rfc5297
+//
+// A nonce, if any, is just another associated data component, conventionally the last one.
+
+use crate::{
+    error::AeadError,
+    mac::cmac::{dbl, Cmac},
+    symmetry::{
+        aes::{Aes128, Aes256},
+        mode::ctr::Ctr,
+        BlockCipher,
+    },
+    utils::{
+        ct::ct_eq,
+        xor::{xor, xor_static},
+    },
+};
+
+// RFC5297 7, S2V takes at most 127 components, the plaintext is the last one
+pub const MAX_AD_COMPONENTS: usize = 126;
+
+#[derive(Debug, Clone)]
+pub struct Siv<C> {
+    mac_cipher: C,
+    ctr_cipher: C,
+}
+
+// AES-SIV-CMAC-256, the key is 32 bytes
+pub type AESSIV = Siv<Aes128>;
+// AES-SIV-CMAC-512, the key is 64 bytes
+pub type AES256SIV = Siv<Aes256>;
+
+impl<C: BlockCipher<16> + Clone> Siv<C>
+where
+    [u8; C::KEY_LENGTH]: Sized,
+{
+    // the key is K1 || K2, K1 for S2V and K2 for CTR
+    pub fn new(key: &[u8; C::KEY_LENGTH * 2]) -> Self {
+        let (k1, k2) = key.split_at(C::KEY_LENGTH);
+        Self::from_ciphers(
+            C::new(k1.try_into().unwrap()),
+            C::new(k2.try_into().unwrap()),
+        )
+    }
+
+    pub fn from_ciphers(mac_cipher: C, ctr_cipher: C) -> Self {
+        Self {
+            mac_cipher,
+            ctr_cipher,
+        }
+    }
+
+    // encrypt `data` in place, return the synthetic IV
+    //
+    // panic if there are more than `MAX_AD_COMPONENTS` associated data components
+    pub fn encrypt(&self, ad: &[&[u8]], data: &mut [u8]) -> [u8; 16] {
+        let siv = self.s2v(ad, data);
+        self.apply_keystream(&siv, data);
+        siv
+    }
+
+    // decrypt `data` in place, it is zeroed if the synthetic IV doesn't match
+    //
+    // panic if there are more than `MAX_AD_COMPONENTS` associated data components
+    pub fn decrypt(&self, ad: &[&[u8]], data: &mut [u8], siv: &[u8; 16]) -> Result<(), AeadError> {
+        self.apply_keystream(siv, data);
+
+        let expected = self.s2v(ad, data);
+        if !ct_eq(&expected, siv) {
+            data.fill(0);
+            return Err(AeadError::BadMac);
+        }
+        Ok(())
+    }
+
+    // RFC5297 2.4
+    fn s2v(&self, ad: &[&[u8]], data: &[u8]) -> [u8; 16] {
+        assert!(ad.len() <= MAX_AD_COMPONENTS);
+
+        let cmac = || Cmac::from_cipher(self.mac_cipher.clone());
+
+        let mut d = {
+            let mut mac = cmac();
+            mac.update_slice(&[0u8; 16]);
+            mac.digest()
+        };
+        for s in ad {
+            let mut mac = cmac();
+            mac.update_slice(s);
+            d = dbl(&d);
+            xor_static(&mut d, &mac.digest());
+        }
+
+        let mut mac = cmac();
+        if data.len() >= 16 {
+            // xorend, only the last block is touched
+            let (head, tail) = data.split_at(data.len() - 16);
+            let mut last: [u8; 16] = tail.try_into().unwrap();
+            xor_static(&mut last, &d);
+            mac.update_slice(head);
+            mac.update_slice(&last);
+        } else {
+            let mut t = dbl(&d);
+            xor(&mut t, data);
+            t[data.len()] ^= 0x80;
+            mac.update_slice(&t);
+        }
+        mac.digest()
+    }
+
+    // RFC5297 2.6, the 31st and 63rd bits from the right are cleared
+    fn apply_keystream(&self, siv: &[u8; 16], data: &mut [u8]) {
+        let mut iv = *siv;
+        iv[8] &= 0x7f;
+        iv[12] &= 0x7f;
+        Ctr::new(self.ctr_cipher.clone(), &iv).apply(data);
+    }
+}
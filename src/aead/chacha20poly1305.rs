@@ -2,11 +2,12 @@ use super::{Aead, Decryptor, Encryptor};
 use crate::{
     error::AeadError,
     mac::{poly1305::Poly1305, Mac},
-    symmetry::chacha::ChaCha20,
+    symmetry::chacha::{hchacha20, ChaCha20},
 };
 
 const KEY_LENGTH: usize = 32;
 const NONCE_LENGTH: usize = 12;
+const XNONCE_LENGTH: usize = 24;
 const BLOCK_LENGTH: usize = 64;
 const MAC_LENGTH: usize = 16;
 
@@ -153,3 +154,38 @@ impl Aead for Chacha20Poly1305 {
         }
     }
 }
+
+// draft-irtf-cfrg-xchacha-03 2.4
+// ChaCha20-Poly1305 with the subkey and nonce derived by HChaCha20
+pub struct XChaCha20Poly1305([u8; KEY_LENGTH]);
+
+impl XChaCha20Poly1305 {
+    // the first 16 bytes of the nonce derive the subkey, the last 8 the ChaCha20 nonce
+    fn subcipher(&self, nonce: &[u8; XNONCE_LENGTH]) -> (Chacha20Poly1305, [u8; NONCE_LENGTH]) {
+        let subkey = hchacha20(&self.0, nonce[..16].try_into().unwrap());
+        let mut chacha_nonce = [0u8; NONCE_LENGTH];
+        chacha_nonce[4..].copy_from_slice(&nonce[16..]);
+        (Chacha20Poly1305::new(&subkey), chacha_nonce)
+    }
+}
+
+impl Aead for XChaCha20Poly1305 {
+    const KEY_LENGTH: usize = KEY_LENGTH;
+    const NONCE_LENGTH: usize = XNONCE_LENGTH;
+    type Encryptor = Chacha20Poly1305Encryptor;
+    type Decryptor = Chacha20Poly1305Decryptor;
+
+    fn new(key: &[u8; Self::KEY_LENGTH]) -> Self {
+        Self(*key)
+    }
+
+    fn encryptor(&self, nonce: &[u8; Self::NONCE_LENGTH], ad: &[u8]) -> Self::Encryptor {
+        let (cipher, nonce) = self.subcipher(nonce);
+        cipher.encryptor(&nonce, ad)
+    }
+
+    fn decryptor(&self, nonce: &[u8; Self::NONCE_LENGTH], ad: &[u8]) -> Self::Decryptor {
+        let (cipher, nonce) = self.subcipher(nonce);
+        cipher.decryptor(&nonce, ad)
+    }
+}
@@ -1,7 +1,14 @@
 use crate::error::AeadError;
+pub mod aesccm;
 pub mod aesgcm;
+pub mod aesgcmsiv;
+pub mod aessiv;
 pub mod chacha20poly1305;
 
+// `Aead` streams the message block by block. Modes that need the whole message before
+// producing any output, like the tag that seeds GCM-SIV's counter, the synthetic IV
+// of SIV or the length encoded in CCM's first CBC-MAC block, can't fit it and encrypt
+// or decrypt the message in place at once instead.
 pub trait Aead {
     const KEY_LENGTH: usize;
     const NONCE_LENGTH: usize;
@@ -0,0 +1,112 @@
+// RFC8032 https://tools.ietf.org/html/rfc8032
+
+use self::point25519::{Point25519, B};
+pub use self::scalar25519::Scalar25519;
+use super::DSA;
+use crate::{
+    error::ECError,
+    hash::{sha512::SHA512, Digest},
+    rng::Rng,
+};
+
+mod point25519;
+mod scalar25519;
+
+// SHA512(prefix || msg), without allocating the concatenation
+fn sha512_with_prefix(prefix: &[&[u8]], msg: &[u8]) -> [u8; 64] {
+    let mut buffer = [0u8; SHA512::BLOCK_LENGTH];
+    let mut len = 0;
+    for p in prefix {
+        buffer[len..len + p.len()].copy_from_slice(p);
+        len += p.len();
+    }
+
+    let n = msg.len().min(SHA512::BLOCK_LENGTH - len);
+    buffer[len..len + n].copy_from_slice(&msg[..n]);
+    if len + n < SHA512::BLOCK_LENGTH {
+        return SHA512::new().digest(&buffer[..len + n]);
+    }
+
+    let mut h = SHA512::new();
+    h.update(&buffer);
+    h.digest(&msg[n..])
+}
+
+#[derive(Debug)]
+pub struct Ed25519 {
+    // clamped secret scalar
+    s: [u8; 32],
+    prefix: [u8; 32],
+    public: [u8; 32],
+}
+
+impl Ed25519 {
+    pub fn new(sk: &[u8; 32]) -> Self {
+        let h = SHA512::new().digest(sk);
+
+        let mut s: [u8; 32] = h[..32].try_into().unwrap();
+        s[0] &= 248;
+        s[31] &= 127;
+        s[31] |= 64;
+
+        let prefix = h[32..].try_into().unwrap();
+        let public = B.mult_scalar(&s).to_bytes();
+
+        Self { s, prefix, public }
+    }
+
+    pub fn generate(rng: &mut impl Rng) -> Self {
+        let mut buf = [0u8; 32];
+        rng.fill_bytes(&mut buf);
+        Self::new(&buf)
+    }
+
+    pub fn to_public(&self) -> [u8; 32] {
+        self.public
+    }
+}
+
+impl DSA for Ed25519 {
+    const PUBLIC_KEY_SIZE: usize = 32;
+    const SIGNATURE_SIZE: usize = 64;
+
+    fn sign(&self, msg: &[u8]) -> [u8; Self::SIGNATURE_SIZE] {
+        let r = Scalar25519::from_wide_bytes(&sha512_with_prefix(&[&self.prefix], msg));
+        let big_r = B.mult_scalar(&r.to_bytes()).to_bytes();
+
+        let k = sha512_with_prefix(&[&big_r, &self.public], msg);
+        let k = Scalar25519::from_wide_bytes(&k);
+
+        let s = Scalar25519::from_bytes_reduce(&self.s);
+        let big_s = r.add(&k.mult(&s));
+
+        let mut sig = [0u8; Self::SIGNATURE_SIZE];
+        sig[..32].copy_from_slice(&big_r);
+        sig[32..].copy_from_slice(&big_s.to_bytes());
+        sig
+    }
+
+    fn verify(
+        pk: &[u8; Self::PUBLIC_KEY_SIZE],
+        msg: &[u8],
+        sig: &[u8; Self::SIGNATURE_SIZE],
+    ) -> Result<(), ECError> {
+        let a = Point25519::from_bytes(pk).ok_or(ECError::InvalidPublicKey)?;
+
+        let (big_r, big_s) = sig.split_at(32);
+        let big_s =
+            Scalar25519::from_bytes(big_s.try_into().unwrap()).ok_or(ECError::InvalidSignature)?;
+
+        let k = sha512_with_prefix(&[big_r, pk], msg);
+        let k = Scalar25519::from_wide_bytes(&k);
+
+        // [S]B - [k]A == R
+        let sb = B.mult_scalar(&big_s.to_bytes());
+        let ka = a.mult_scalar(&k.to_bytes());
+        if sb.add(&ka.neg()).to_bytes() != big_r {
+            return Err(ECError::InvalidSignature);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,200 @@
+use crate::ec::x25519::fe25519::{Fe25519, ONE, ZERO};
+
+// d = -121665 / 121666
+const D: Fe25519 = Fe25519([
+    0x34dca135978a3,
+    0x1a8283b156ebd,
+    0x5e7a26001c029,
+    0x739c663a03cbb,
+    0x52036cee2b6ff,
+]);
+
+const D2: Fe25519 = Fe25519([
+    0x69b9426b2f159,
+    0x35050762add7a,
+    0x3cf44c0038052,
+    0x6738cc7407977,
+    0x2406d9dc56dff,
+]);
+
+// 2^((P25519 - 1) / 4)
+const SQRT_M1: Fe25519 = Fe25519([
+    0x61b274a0ea0b0,
+    0x0d5a5fc8f189d,
+    0x7ef5e9cbd0c60,
+    0x78595a6804c9e,
+    0x2b8324804fc1d,
+]);
+
+// Point on -x^2 + y^2 = 1 + d * x^2 * y^2 mod P25519
+// extended coordinates (X, Y, Z, T): (x, y) = (X/Z, Y/Z), x * y = T/Z
+// identity is (0, 1, 1, 0)
+#[derive(Debug, Copy, Clone)]
+pub struct Point25519 {
+    x: Fe25519,
+    y: Fe25519,
+    z: Fe25519,
+    t: Fe25519,
+}
+
+pub const B: Point25519 = Point25519 {
+    x: Fe25519([
+        0x62d608f25d51a,
+        0x412a4b4f6592a,
+        0x75b7171a4b31d,
+        0x1ff60527118fe,
+        0x216936d3cd6e5,
+    ]),
+    y: Fe25519([
+        0x6666666666658,
+        0x4cccccccccccc,
+        0x1999999999999,
+        0x3333333333333,
+        0x6666666666666,
+    ]),
+    z: ONE,
+    t: Fe25519([
+        0x68ab3a5b7dda3,
+        0x00eea2a5eadbb,
+        0x2af8df483c27e,
+        0x332b375274732,
+        0x67875f0fd78b7,
+    ]),
+};
+
+const IDENTITY: Point25519 = Point25519 {
+    x: ZERO,
+    y: ONE,
+    z: ONE,
+    t: ZERO,
+};
+
+impl Point25519 {
+    // https://hyperelliptic.org/EFD/g1p/auto-twisted-extended-1.html#addition-add-2008-hwcd-3
+    // unified, also works for doubling and identity
+    pub fn add(&self, b: &Point25519) -> Point25519 {
+        let a_ = self.y.sub(&self.x).mult(&b.y.sub(&b.x));
+        let b_ = self.y.add(&self.x).mult(&b.y.add(&b.x));
+        let c = self.t.mult(&D2).mult(&b.t);
+        let d = self.z.add(&self.z).mult(&b.z);
+        let e = b_.sub(&a_);
+        let f = d.sub(&c);
+        let g = d.add(&c);
+        let h = b_.add(&a_);
+
+        Point25519 {
+            x: e.mult(&f),
+            y: g.mult(&h),
+            z: f.mult(&g),
+            t: e.mult(&h),
+        }
+    }
+
+    // https://hyperelliptic.org/EFD/g1p/auto-twisted-extended-1.html#doubling-dbl-2008-hwcd
+    pub fn double(&self) -> Point25519 {
+        let a = self.x.square();
+        let b = self.y.square();
+        let c = self.z.square().mult_small(2);
+        let h = a.add(&b);
+        let e = h.sub(&self.x.add(&self.y).square());
+        let g = a.sub(&b);
+        let f = c.add(&g);
+
+        Point25519 {
+            x: e.mult(&f),
+            y: g.mult(&h),
+            z: f.mult(&g),
+            t: e.mult(&h),
+        }
+    }
+
+    pub fn neg(&self) -> Point25519 {
+        Point25519 {
+            x: self.x.neg(),
+            y: self.y,
+            z: self.z,
+            t: self.t.neg(),
+        }
+    }
+
+    // constant time
+    // if flag == 0, returns a
+    // if flag == 1, returns b
+    fn choose(flag: bool, a: &Point25519, b: &Point25519) -> Point25519 {
+        Point25519 {
+            x: Fe25519::choose(flag, &a.x, &b.x),
+            y: Fe25519::choose(flag, &a.y, &b.y),
+            z: Fe25519::choose(flag, &a.z, &b.z),
+            t: Fe25519::choose(flag, &a.t, &b.t),
+        }
+    }
+
+    // double-and-add-always, the sequence of field operations is independent of `n`.
+    // `n` is little-endian
+    pub fn mult_scalar(&self, n: &[u8; 32]) -> Point25519 {
+        let mut r = IDENTITY;
+        for i in (0..256).rev() {
+            let bit = (n[i / 8] >> (i % 8)) & 1 == 1;
+            r = r.double();
+            let s = r.add(self);
+            r = Point25519::choose(bit, &r, &s);
+        }
+        r
+    }
+
+    // RFC8032 5.1.2
+    pub fn to_bytes(self) -> [u8; 32] {
+        let z_inv = self.z.inverse();
+        let x = self.x.mult(&z_inv);
+        let mut r = self.y.mult(&z_inv).to_bytes();
+        r[31] |= (x.is_negative() as u8) << 7;
+        r
+    }
+
+    // RFC8032 5.1.3
+    // public input, so branches are fine here
+    pub fn from_bytes(b: &[u8; 32]) -> Option<Point25519> {
+        let y = Fe25519::from_bytes(b);
+        let x_0 = b[31] >> 7 == 1;
+
+        // y must be < P25519
+        let mut canonical = *b;
+        canonical[31] &= 0x7f;
+        if y.to_bytes() != canonical {
+            return None;
+        }
+
+        // x^2 = (y^2 - 1) / (d * y^2 + 1) = u / v
+        let yy = y.square();
+        let u = yy.sub(&ONE);
+        let v = D.mult(&yy).add(&ONE);
+
+        // x = u * v^3 * (u * v^7)^((P25519 - 5) / 8)
+        let v3 = v.square().mult(&v);
+        let v7 = v3.square().mult(&v);
+        let mut x = u.mult(&v3).mult(&u.mult(&v7).pow22523());
+
+        let vxx = v.mult(&x.square());
+        if vxx.sub(&u).is_zero() {
+            // x is a square root
+        } else if vxx.add(&u).is_zero() {
+            x = x.mult(&SQRT_M1);
+        } else {
+            return None;
+        }
+
+        if x.is_zero() && x_0 {
+            return None;
+        }
+        if x.is_negative() != x_0 {
+            x = x.neg();
+        }
+
+        Some(Point25519 {
+            x,
+            y,
+            z: ONE,
+            t: x.mult(&y),
+        })
+    }
+}
@@ -0,0 +1,195 @@
+const LIMBS: usize = 8;
+
+// L = order of the base point B
+// L = 2^252 + 27742317777372353535851937790883648493
+pub const L: [u32; LIMBS] = [
+    0x5cf5d3ed, 0x5812631a, 0xa2f79cd6, 0x14def9de, //
+    0x00000000, 0x00000000, 0x00000000, 0x10000000,
+];
+
+// R2 = 2^512 mod L, used to convert into Montgomery form
+const R2: [u32; LIMBS] = [
+    0x449c0f01, 0xa40611e3, 0x68859347, 0xd00e1ba7, //
+    0x17f5be65, 0xceec73d2, 0x7c309a3d, 0x0399411b,
+];
+
+// -L^-1 mod 2^32
+const N0_INV: u32 = 0x12547e1b;
+
+const ONE: [u32; LIMBS] = [1, 0, 0, 0, 0, 0, 0, 0];
+
+// integer modulo L, little-endian u32 limbs
+// value must be < L
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Scalar25519(pub(crate) [u32; LIMBS]);
+
+impl Scalar25519 {
+    // return (a + b mod 2^256, carry)
+    fn add_no_reduce(a: &[u32; LIMBS], b: &[u32; LIMBS]) -> ([u32; LIMBS], u32) {
+        let mut r = [0u32; LIMBS];
+        let mut carry = 0u64;
+        for i in 0..LIMBS {
+            let v = (a[i] as u64) + (b[i] as u64) + carry;
+            r[i] = v as u32;
+            carry = v >> 32;
+        }
+        (r, carry as u32)
+    }
+
+    // return (a - b mod 2^256, borrow)
+    fn sub_no_reduce(a: &[u32; LIMBS], b: &[u32; LIMBS]) -> ([u32; LIMBS], u32) {
+        let mut r = [0u32; LIMBS];
+        let mut borrow = 0i64;
+        for i in 0..LIMBS {
+            let v = (a[i] as i64) - (b[i] as i64) + borrow;
+            r[i] = v as u32;
+            borrow = v >> 32;
+        }
+        (r, (borrow & 1) as u32)
+    }
+
+    // precondition: `v + carry * 2^256 < 2 * L`
+    // return `(v + carry * 2^256) mod L`
+    fn reduce_once(v: &[u32; LIMBS], carry: u32) -> Scalar25519 {
+        let (r, borrow) = Scalar25519::sub_no_reduce(v, &L);
+        // keep v only if v < L, constant time
+        let mask = 0u32.wrapping_sub(carry ^ borrow);
+        let mut out = r;
+        out.iter_mut()
+            .zip(v)
+            .for_each(|(x, y)| *x ^= mask & (*x ^ y));
+        Scalar25519(out)
+    }
+
+    // return a * b * 2^-256 mod L
+    // precondition: a < 2^256, b < L
+    fn mont_mult(a: &[u32; LIMBS], b: &[u32; LIMBS]) -> [u32; LIMBS] {
+        let mut t = [0u32; LIMBS + 2];
+        for &bi in b {
+            // t += a * b[i]
+            let mut carry = 0u64;
+            for j in 0..LIMBS {
+                let v = (t[j] as u64) + (a[j] as u64) * (bi as u64) + carry;
+                t[j] = v as u32;
+                carry = v >> 32;
+            }
+            let v = (t[LIMBS] as u64) + carry;
+            t[LIMBS] = v as u32;
+            t[LIMBS + 1] = (v >> 32) as u32;
+
+            // t = (t + m * L) / 2^32
+            let m = t[0].wrapping_mul(N0_INV);
+            let mut carry = ((t[0] as u64) + (m as u64) * (L[0] as u64)) >> 32;
+            for j in 1..LIMBS {
+                let v = (t[j] as u64) + (m as u64) * (L[j] as u64) + carry;
+                t[j - 1] = v as u32;
+                carry = v >> 32;
+            }
+            let v = (t[LIMBS] as u64) + carry;
+            t[LIMBS - 1] = v as u32;
+            t[LIMBS] = t[LIMBS + 1] + (v >> 32) as u32;
+        }
+
+        let mut v = [0u32; LIMBS];
+        v.copy_from_slice(&t[..LIMBS]);
+        Scalar25519::reduce_once(&v, t[LIMBS]).0
+    }
+
+    pub fn add(&self, b: &Scalar25519) -> Scalar25519 {
+        let (v, carry) = Scalar25519::add_no_reduce(&self.0, &b.0);
+        Scalar25519::reduce_once(&v, carry)
+    }
+
+    pub fn mult(&self, b: &Scalar25519) -> Scalar25519 {
+        let ab = Scalar25519::mont_mult(&self.0, &b.0);
+        Scalar25519(Scalar25519::mont_mult(&ab, &R2))
+    }
+
+    fn load(b: &[u8; 32]) -> [u32; LIMBS] {
+        let mut v = [0u32; LIMBS];
+        for (x, c) in v.iter_mut().zip(b.as_chunks().0) {
+            *x = u32::from_le_bytes(*c);
+        }
+        v
+    }
+
+    // little-endian.
+    pub fn to_bytes(self) -> [u8; 32] {
+        let mut r = [0u8; 32];
+        for (c, x) in r.as_chunks_mut().0.iter_mut().zip(self.0) {
+            *c = x.to_le_bytes();
+        }
+        r
+    }
+
+    // little-endian, returns None if value >= L
+    pub fn from_bytes(b: &[u8; 32]) -> Option<Self> {
+        let v = Scalar25519::load(b);
+        let (_, borrow) = Scalar25519::sub_no_reduce(&v, &L);
+        if borrow == 0 {
+            return None;
+        }
+        Some(Scalar25519(v))
+    }
+
+    // little-endian, value is reduced modulo L
+    pub fn from_bytes_reduce(b: &[u8; 32]) -> Self {
+        let v = Scalar25519::load(b);
+        // v * 2^256 * 2^-256
+        let v = Scalar25519::mont_mult(&v, &R2);
+        Scalar25519(Scalar25519::mont_mult(&v, &ONE))
+    }
+
+    // little-endian, value is reduced modulo L
+    pub fn from_wide_bytes(b: &[u8; 64]) -> Self {
+        let lo = Scalar25519::from_bytes_reduce(b[..32].try_into().unwrap());
+        let hi = Scalar25519::load(b[32..].try_into().unwrap());
+
+        // hi * 2^256 = mont_mult(hi, 2^512)
+        let hi = Scalar25519(Scalar25519::mont_mult(&hi, &R2));
+        hi.add(&lo)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Scalar25519, L, ONE};
+
+    #[test]
+    fn test_scalar25519_reduce() {
+        let l = Scalar25519(L).to_bytes();
+        assert_eq!(Scalar25519::from_bytes(&l), None);
+        assert_eq!(Scalar25519::from_bytes_reduce(&l), Scalar25519([0; 8]));
+
+        // 2^256 - 1 mod L
+        assert_eq!(
+            Scalar25519::from_bytes_reduce(&[0xff; 32]),
+            Scalar25519([
+                0x8d98951c, 0xd6ec3174, 0x737dcf70, 0xc6ef5bf4, //
+                0xfffffffe, 0xffffffff, 0xffffffff, 0x0fffffff,
+            ])
+        );
+    }
+
+    #[test]
+    fn test_scalar25519_from_wide_bytes() {
+        let mut b = [0u8; 64];
+        b.iter_mut().enumerate().for_each(|(i, x)| *x = i as u8);
+        assert_eq!(
+            Scalar25519::from_wide_bytes(&b),
+            Scalar25519([
+                0x82623c7a, 0xa0372df0, 0x0db62350, 0xcce62854, //
+                0xc3d46159, 0x7a932112, 0x74b5e0da, 0x0572d0e4,
+            ])
+        );
+    }
+
+    #[test]
+    fn test_scalar25519_mult() {
+        let a = Scalar25519([1, 2, 3, 4, 5, 6, 7, 8]);
+        let b = Scalar25519([0xfffffffe, 0, 0, 0, 0xfffffffe, 0, 0, 0x0fffffff]);
+        assert_eq!(a.mult(&Scalar25519(ONE)), a);
+        assert_eq!(a.mult(&b), b.mult(&a));
+        assert_eq!(a.mult(&b.add(&a)), a.mult(&b).add(&a.mult(&a)));
+    }
+}
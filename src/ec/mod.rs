@@ -1,6 +1,8 @@
 use crate::{error::ECError, rng::Rng};
 
+pub mod ed25519;
 pub mod p256;
+pub mod x25519;
 
 pub trait ECDH {
     const POINT_SIZE: usize;
@@ -11,3 +13,15 @@ pub trait ECDH {
     fn to_public(&self) -> [u8; Self::POINT_SIZE];
     fn exchange(&self, gy: &[u8; Self::POINT_SIZE]) -> Result<[u8; Self::INT_SIZE], ECError>;
 }
+
+pub trait DSA {
+    const PUBLIC_KEY_SIZE: usize;
+    const SIGNATURE_SIZE: usize;
+
+    fn sign(&self, msg: &[u8]) -> [u8; Self::SIGNATURE_SIZE];
+    fn verify(
+        pk: &[u8; Self::PUBLIC_KEY_SIZE],
+        msg: &[u8],
+        sig: &[u8; Self::SIGNATURE_SIZE],
+    ) -> Result<(), ECError>;
+}
@@ -1,6 +1,6 @@
 use crate::utils::endian::{EndianConvertion, LittleEndian};
 
-const LIMBS: usize = 8;
+pub(crate) const LIMBS: usize = 8;
 
 // 2^32-radix: value = v[0] + 2^32 v[1] + ... + 2^124 v[7]
 // value must be < P256
@@ -22,21 +22,30 @@ impl Int256 {
         self.0 != b.0
     }
 
+    // constant time, return true if self == 0
+    pub fn is_zero(&self) -> bool {
+        let acc = self.0.iter().fold(0, |acc, x| acc | x);
+        // top bit of `acc | -acc` is set iff acc != 0
+        ((acc | acc.wrapping_neg()) >> 31) == 0
+    }
+
+    // constant time
     // if flag == 0, returns a
     // if flag == 1, returns b
     pub fn choose(flag: bool, a: &Int256, b: &Int256) -> Int256 {
-        if !flag {
-            *a
-        } else {
-            *b
+        let mask = 0u32.wrapping_sub(flag as u32);
+        let mut v = ZERO;
+        for i in 0..LIMBS {
+            v.0[i] = a.0[i] ^ (mask & (a.0[i] ^ b.0[i]));
         }
+        v
     }
 
     // return (value, carry) where
     // value = self + b mod 2^256
     // carry = if self + b < P256 { 0 } else { 1 }
     // i.e. self + b == value + 2^256 * carry
-    fn add_no_reduce(&self, b: &Int256) -> (Int256, u32) {
+    pub(crate) fn add_no_reduce(&self, b: &Int256) -> (Int256, u32) {
         let mut v = ZERO;
 
         // invariant: carry <= 1
@@ -54,7 +63,7 @@ impl Int256 {
     // value = self - b mod 2^256
     // carry = if self > b { 0 } else { 1 }
     // i.e. self - b == value - 2^256 * carry
-    fn sub_no_reduce(&self, b: &Int256) -> (Int256, u32) {
+    pub(crate) fn sub_no_reduce(&self, b: &Int256) -> (Int256, u32) {
         let mut v = Int256([0u32; LIMBS]);
 
         // invariant: carry_sub <= 1
@@ -87,6 +96,9 @@ impl Int256 {
     }
 
     pub fn add(&self, b: &Int256) -> Int256 {
+        #[cfg(test)]
+        counter::tick();
+
         let (v, carry) = self.add_no_reduce(b);
         v.reduce_once(carry)
     }
@@ -97,6 +109,9 @@ impl Int256 {
     }
 
     pub fn sub(&self, b: &Int256) -> Int256 {
+        #[cfg(test)]
+        counter::tick();
+
         let (v, carry_sub) = self.sub_no_reduce(b);
         // if self - b < 0, carry_sub == 1 and v == 2^256 + self - b
         let (v2, _carry_add) = v.add_no_reduce(&P256);
@@ -105,6 +120,9 @@ impl Int256 {
     }
 
     pub fn mult(&self, b: &Int256) -> Int256 {
+        #[cfg(test)]
+        counter::tick();
+
         let mut w = [0u64; LIMBS * 2];
         for i in 0..LIMBS {
             for j in 0..LIMBS {
@@ -249,6 +267,32 @@ impl Int256 {
         y256_224.mult(&z192).mult(&y96_2)
     }
 
+    // return Some(r) where r^2 == self, or None if self is not a square.
+    // P256 = 3 mod 4, so r = self^((P256 + 1) / 4)
+    pub fn sqrt(&self) -> Option<Int256> {
+        // (P256 + 1) / 4 = 2^254 - 2^222 + 2^190 + 2^94
+        const E: Int256 = Int256([
+            0x00000000, 0x00000000, 0x40000000, 0x00000000, //
+            0x00000000, 0x40000000, 0xc0000000, 0x3fffffff,
+        ]);
+
+        // exponent is public, so square-and-multiply is fine here
+        let mut r = ONE;
+        for i in (0..LIMBS).rev() {
+            for j in (0..32).rev() {
+                r = r.square();
+                if (E.0[i] >> j) & 1 == 1 {
+                    r = r.mult(self);
+                }
+            }
+        }
+
+        if r.square().not_equal(self) {
+            return None;
+        }
+        Some(r)
+    }
+
     #[cfg(test)]
     pub fn divide_by_2(&self) -> Int256 {
         let is_odd = self.0[0] & 1;
@@ -288,6 +332,27 @@ impl Int256 {
     }
 }
 
+// counts field operations of the current thread, used to check that
+// secret dependent routines always run the same sequence of operations
+#[cfg(test)]
+pub(crate) mod counter {
+    extern crate std;
+
+    use core::cell::Cell;
+
+    std::thread_local! {
+        static COUNTER: Cell<usize> = const { Cell::new(0) };
+    }
+
+    pub(crate) fn tick() {
+        COUNTER.with(|c| c.set(c.get() + 1));
+    }
+
+    pub(crate) fn take() -> usize {
+        COUNTER.with(|c| c.replace(0))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::{Int256, ONE, P256, ZERO};
@@ -320,6 +385,18 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_int256_choose() {
+        for a in VALUES_256.iter() {
+            assert_eq!(a.is_zero(), *a == ZERO);
+
+            for b in VALUES_256.iter() {
+                assert_eq!(Int256::choose(false, a, b), *a);
+                assert_eq!(Int256::choose(true, a, b), *b);
+            }
+        }
+    }
+
     #[test]
     fn test_int256_reduce_once() {
         // FIXME more tests
@@ -430,6 +507,21 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_int256_sqrt() {
+        assert_eq!(ZERO.sqrt(), Some(ZERO));
+        assert_eq!(ONE.sqrt().map(|r| r.square()), Some(ONE));
+
+        // -1 is not a square since P256 = 3 mod 4
+        assert_eq!(ZERO.sub(&ONE).sqrt(), None);
+
+        for a in VALUES_256.iter() {
+            let a2 = a.square();
+            let r = a2.sqrt().unwrap();
+            assert!(r == *a || r == ZERO.sub(a));
+        }
+    }
+
     #[test]
     fn test_int256_divide_by_2() {
         for a in VALUES_256.iter() {
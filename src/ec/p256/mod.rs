@@ -1,10 +1,16 @@
-pub use self::point256::G;
 use self::{int256::Int256, point256::NPoint256};
-use super::ECDH;
-use crate::{error::ECError, rng::Rng};
+pub use self::{point256::G, scalar256::Scalar256};
+use super::{DSA, ECDH};
+use crate::{
+    error::ECError,
+    hash::sha256::{sha256, SHA256},
+    mac::hmac::hmac,
+    rng::Rng,
+};
 
 mod int256;
 mod point256;
+mod scalar256;
 
 #[derive(Debug)]
 pub struct P256 {
@@ -24,11 +30,10 @@ impl ECDH for P256 {
         let mut buf = [0u8; 32];
         loop {
             rng.fill_bytes(&mut buf);
-            let x = Int256::from_bytes(&buf).unwrap();
-            let xx = x.reduce_once(0);
-            let x_is_okay = xx.not_equal(&x);
-            if !x_is_okay {
-                return Self { x };
+            // private key must be in [1, N256 - 1]
+            match Scalar256::from_bytes(&buf) {
+                Some(x) if !x.is_zero() => return Self { x: x.0 },
+                _ => continue,
             }
         }
     }
@@ -41,9 +46,137 @@ impl ECDH for P256 {
     }
 
     fn exchange(&self, gy: &[u8; 65]) -> Result<[u8; 32], ECError> {
-        let gy = NPoint256::from_uncompressed_bytes(gy).ok_or(ECError::InvalidPublicKey)?;
+        self.exchange_sec1(gy)
+    }
+}
+
+impl P256 {
+    pub fn to_compressed_public(&self) -> [u8; 33] {
+        point256::G
+            .mult_scalar(&self.x)
+            .normalize()
+            .to_compressed_bytes()
+    }
+
+    // accept both 33 bytes compressed and 65 bytes uncompressed SEC1 point
+    pub fn exchange_sec1(&self, gy: &[u8]) -> Result<[u8; 32], ECError> {
+        let gy = NPoint256::from_bytes(gy).ok_or(ECError::InvalidPublicKey)?;
         let gy = gy.to_point();
         let gxy = gy.mult_scalar(&self.x).normalize();
         Ok(gxy.x.to_bytes())
     }
 }
+
+pub fn compress(point: &[u8; 65]) -> Result<[u8; 33], ECError> {
+    let p = NPoint256::from_uncompressed_bytes(point).ok_or(ECError::InvalidPublicKey)?;
+    Ok(p.to_compressed_bytes())
+}
+
+pub fn decompress(point: &[u8; 33]) -> Result<[u8; 65], ECError> {
+    let p = NPoint256::from_compressed_bytes(point).ok_or(ECError::InvalidPublicKey)?;
+    Ok(p.to_uncompressed_bytes())
+}
+
+// RFC6979 deterministic nonce, instantiated with HMAC-SHA256
+struct NonceGenerator {
+    k: [u8; 32],
+    v: [u8; 32],
+}
+
+impl NonceGenerator {
+    fn new(x: &[u8; 32], h: &[u8; 32]) -> Self {
+        let mut g = NonceGenerator {
+            k: [0u8; 32],
+            v: [1u8; 32],
+        };
+
+        // V || 0x00 || int2octets(x) || bits2octets(h1)
+        let mut buffer = [0u8; 32 + 1 + 32 + 32];
+        buffer[33..65].copy_from_slice(x);
+        buffer[65..].copy_from_slice(h);
+
+        for i in 0..2 {
+            buffer[..32].copy_from_slice(&g.v);
+            buffer[32] = i;
+            g.k = hmac::<SHA256>(&g.k, &buffer);
+            g.v = hmac::<SHA256>(&g.k, &g.v);
+        }
+
+        g
+    }
+
+    fn next(&mut self) -> Scalar256 {
+        loop {
+            self.v = hmac::<SHA256>(&self.k, &self.v);
+            let k = Scalar256::from_bytes(&self.v);
+
+            // prepare for the next candidate in case of rejection
+            let mut buffer = [0u8; 32 + 1];
+            buffer[..32].copy_from_slice(&self.v);
+            self.k = hmac::<SHA256>(&self.k, &buffer);
+            self.v = hmac::<SHA256>(&self.k, &self.v);
+
+            match k {
+                Some(k) if !k.is_zero() => return k,
+                _ => continue,
+            }
+        }
+    }
+}
+
+// ECDSA with SHA-256, signature is r || s in big-endian
+impl DSA for P256 {
+    const PUBLIC_KEY_SIZE: usize = 65;
+    const SIGNATURE_SIZE: usize = 64;
+
+    fn sign(&self, msg: &[u8]) -> [u8; 64] {
+        let d = Scalar256::from_bytes_reduce(&self.x.to_bytes());
+        let z = Scalar256::from_bytes_reduce(&sha256(msg));
+
+        let mut nonce = NonceGenerator::new(&d.to_bytes(), &z.to_bytes());
+        loop {
+            let k = nonce.next();
+
+            let p = point256::G.mult_scalar(&k.0).normalize();
+            let r = Scalar256::from_bytes_reduce(&p.x.to_bytes());
+            let s = k.inverse().mult(&z.add(&r.mult(&d)));
+            if r.is_zero() || s.is_zero() {
+                continue;
+            }
+
+            let mut sig = [0u8; 64];
+            sig[..32].copy_from_slice(&r.to_bytes());
+            sig[32..].copy_from_slice(&s.to_bytes());
+            return sig;
+        }
+    }
+
+    fn verify(pk: &[u8; 65], msg: &[u8], sig: &[u8; 64]) -> Result<(), ECError> {
+        let q = NPoint256::from_uncompressed_bytes(pk).ok_or(ECError::InvalidPublicKey)?;
+
+        let r = Scalar256::from_bytes(sig[..32].try_into().unwrap());
+        let s = Scalar256::from_bytes(sig[32..].try_into().unwrap());
+        let (r, s) = match (r, s) {
+            (Some(r), Some(s)) if !r.is_zero() && !s.is_zero() => (r, s),
+            _ => return Err(ECError::InvalidSignature),
+        };
+
+        let z = Scalar256::from_bytes_reduce(&sha256(msg));
+        let w = s.inverse();
+        let (u1, u2) = (z.mult(&w), r.mult(&w));
+
+        let p = point256::G
+            .mult_scalar(&u1.0)
+            .add_full(&q.to_point().mult_scalar(&u2.0));
+        if p.is_infinity() {
+            return Err(ECError::InvalidSignature);
+        }
+
+        let x = Scalar256::from_bytes_reduce(&p.normalize().x.to_bytes());
+        if x == r {
+            Ok(())
+        } else {
+            Err(ECError::InvalidSignature)
+        }
+    }
+}
@@ -45,7 +45,7 @@ impl Point256 {
     }
 
     // compute `self + self`
-    // self.z must not zero.
+    // if self.z is zero, the result z is also zero.
     fn double(&self) -> Point256 {
         // delta = Z1^2
         let delta = self.z.square();
@@ -100,23 +100,72 @@ impl Point256 {
         Point256 { x, y, z }
     }
 
+    // compute `self + b` for arbitrary points, including INFTY and `self == b`.
+    // it branches on the inputs, so only use it on public values.
+    pub fn add_full(&self, b: &Point256) -> Point256 {
+        if self.z == ZERO {
+            return *b;
+        }
+        if b.z == ZERO {
+            return *self;
+        }
+
+        let z1z1 = self.z.square();
+        let z2z2 = b.z.square();
+        let u1 = self.x.mult(&z2z2);
+        let u2 = b.x.mult(&z1z1);
+        let s1 = self.y.mult(&b.z).mult(&z2z2);
+        let s2 = b.y.mult(&self.z).mult(&z1z1);
+
+        match (u1 == u2, s1 == s2) {
+            (true, true) => self.double(),
+            (true, false) => INFTY,
+            _ => self.add(b),
+        }
+    }
+
+    pub fn is_infinity(&self) -> bool {
+        self.z == ZERO
+    }
+
+    // constant time
+    // if flag == 0, returns a
+    // if flag == 1, returns b
+    fn choose(flag: bool, a: &Point256, b: &Point256) -> Point256 {
+        Point256 {
+            x: Int256::choose(flag, &a.x, &b.x),
+            y: Int256::choose(flag, &a.y, &b.y),
+            z: Int256::choose(flag, &a.z, &b.z),
+        }
+    }
+
+    // compute `self + b` in constant time.
+    // INFTY is handled, but `self == b` is not.
+    fn add_ct(&self, b: &Point256) -> Point256 {
+        let r = self.add(b);
+        let r = Point256::choose(self.z.is_zero(), &r, b);
+        Point256::choose(b.z.is_zero(), &r, self)
+    }
+
+    // Montgomery ladder, the sequence of field operations is independent of `n`.
     pub fn mult_scalar(&self, n: &Int256) -> Point256 {
-        let mut ret = INFTY;
+        // invariant: r1 == r0 + self, so r0 == r1 never happens in `add_ct`
+        let (mut r0, mut r1) = (INFTY, *self);
         for i in (0..8).rev() {
             for j in (0..32).rev() {
-                let bit = (n.0[i] >> j) & 1;
-                let ret2 = ret.double();
-                ret = if bit == 0 {
-                    ret2
-                } else if ret2 == INFTY {
-                    *self
-                } else {
-                    ret2.add(self)
-                };
+                let bit = (n.0[i] >> j) & 1 == 1;
+
+                // bit == 0: (r0, r1) = (2 * r0, r0 + r1)
+                // bit == 1: (r0, r1) = (r0 + r1, 2 * r1)
+                let a = Point256::choose(bit, &r0, &r1);
+                let b = Point256::choose(bit, &r1, &r0);
+                let (a, b) = (a.double(), a.add_ct(&b));
+                r0 = Point256::choose(bit, &a, &b);
+                r1 = Point256::choose(bit, &b, &a);
             }
         }
 
-        ret
+        r0
     }
 }
 
@@ -169,6 +218,45 @@ impl NPoint256 {
         Some(p)
     }
 
+    pub fn from_compressed_bytes(data: &[u8; 33]) -> Option<NPoint256> {
+        let y_is_odd = match data[0] {
+            0x02 => 0,
+            0x03 => 1,
+            _ => return None,
+        };
+
+        let x = Int256::from_bytes(data[1..].try_into().unwrap())?;
+        if x.reduce_once_zero().not_equal(&x) {
+            return None;
+        }
+
+        // y^2 = x^3 - 3 * x + B
+        let x3 = x.square().mult(&x);
+        let y2 = x3.sub(&x.double().add(&x)).add(&B);
+        let y = y2.sqrt()?;
+
+        let y = Int256::choose(y.0[0] & 1 != y_is_odd, &y, &ZERO.sub(&y));
+
+        Some(NPoint256 { x, y })
+    }
+
+    // accept both compressed and uncompressed form
+    pub fn from_bytes(data: &[u8]) -> Option<NPoint256> {
+        match data.len() {
+            33 => NPoint256::from_compressed_bytes(data.try_into().unwrap()),
+            65 => NPoint256::from_uncompressed_bytes(data.try_into().unwrap()),
+            _ => None,
+        }
+    }
+
+    pub fn to_compressed_bytes(&self) -> [u8; 33] {
+        // 0x02 | lowest bit of y || self.x (big endian)
+        let mut b = [0u8; 33];
+        b[0] = 0x02 | (self.y.0[0] & 1) as u8;
+        b[1..].copy_from_slice(&self.x.to_bytes());
+        b
+    }
+
     pub fn to_uncompressed_bytes(&self) -> [u8; 65] {
         // 0x04 || self.x (big endian) || self.y (big endian)
         let mut b = [0u8; 65];
@@ -178,3 +266,57 @@ impl NPoint256 {
         b
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{Point256, G};
+    use crate::ec::p256::{
+        int256::{counter, Int256, ONE, ZERO},
+        scalar256::N256,
+    };
+
+    static SCALARS: &[Int256] = &[
+        ZERO,
+        ONE,
+        Int256([2, 0, 0, 0, 0, 0, 0, 0]),
+        Int256([1, 2, 3, 4, 5, 6, 7, 8]),
+        Int256([0, 0, 0, 0, 0, 0, 0, 0x80000000]),
+        Int256([0xffffffff; 8]),
+        N256,
+    ];
+
+    #[test]
+    fn test_mult_scalar() {
+        assert!(G.mult_scalar(&ZERO).is_infinity());
+        assert!(G.mult_scalar(&N256).is_infinity());
+
+        let g = G.mult_scalar(&ONE).normalize();
+        assert_eq!((g.x, g.y), (G.x, G.y));
+
+        let (n_1, _) = N256.sub_no_reduce(&ONE);
+        let neg_g = G.mult_scalar(&n_1).normalize();
+        assert_eq!((neg_g.x, neg_g.y), (G.x, ZERO.sub(&G.y)));
+
+        let (n_2, _) = N256.sub_no_reduce(&Int256([2, 0, 0, 0, 0, 0, 0, 0]));
+        let p = G.mult_scalar(&n_2).add_full(&G).normalize();
+        assert_eq!((p.x, p.y), (neg_g.x, neg_g.y));
+    }
+
+    #[test]
+    fn test_mult_scalar_operation_count() {
+        let p: Point256 = G.double();
+
+        counter::take();
+        G.mult_scalar(&SCALARS[0]);
+        let expected = counter::take();
+        assert_ne!(expected, 0);
+
+        for n in SCALARS.iter() {
+            G.mult_scalar(n);
+            assert_eq!(counter::take(), expected);
+
+            p.mult_scalar(n);
+            assert_eq!(counter::take(), expected);
+        }
+    }
+}
@@ -0,0 +1,229 @@
+use super::int256::{Int256, LIMBS, ONE, ZERO};
+
+// N256 = order of the base point G
+// N256 = 2^256 - 2^224 + 2^192 - 0x4319055258e8617b0c46353d039cdaaf
+pub const N256: Int256 = Int256([
+    0xfc632551, 0xf3b9cac2, 0xa7179e84, 0xbce6faad, //
+    0xffffffff, 0xffffffff, 0x00000000, 0xffffffff,
+]);
+
+// R2 = 2^512 mod N256, used to convert into Montgomery form
+const R2: Int256 = Int256([
+    0xbe79eea2, 0x83244c95, 0x49bd6fa6, 0x4699799c, //
+    0x2b6bec59, 0x2845b239, 0xf3d95620, 0x66e12d94,
+]);
+
+// -N256^-1 mod 2^32
+const N0_INV: u32 = 0xee00bc4f;
+
+// integer modulo N256
+// value must be < N256
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Scalar256(pub(crate) Int256);
+
+impl Scalar256 {
+    // precondition: `v + carry * 2^256 < 2 * N256`
+    // return `(v + carry * 2^256) mod N256`
+    fn reduce_once(v: &Int256, carry: u32) -> Scalar256 {
+        let (r, carry_sub) = v.sub_no_reduce(&N256);
+        Scalar256(Int256::choose(carry ^ carry_sub != 0, &r, v))
+    }
+
+    // return a * b * 2^-256 mod N256
+    // precondition: a, b < N256
+    fn mont_mult(a: &Int256, b: &Int256) -> Int256 {
+        let (a, b, n) = (&a.0, &b.0, &N256.0);
+
+        let mut t = [0u32; LIMBS + 2];
+        for &bi in b {
+            // t += a * b[i]
+            let mut carry = 0u64;
+            for j in 0..LIMBS {
+                let v = (t[j] as u64) + (a[j] as u64) * (bi as u64) + carry;
+                t[j] = v as u32;
+                carry = v >> 32;
+            }
+            let v = (t[LIMBS] as u64) + carry;
+            t[LIMBS] = v as u32;
+            t[LIMBS + 1] = (v >> 32) as u32;
+
+            // t = (t + m * N256) / 2^32
+            let m = t[0].wrapping_mul(N0_INV);
+            let mut carry = ((t[0] as u64) + (m as u64) * (n[0] as u64)) >> 32;
+            for j in 1..LIMBS {
+                let v = (t[j] as u64) + (m as u64) * (n[j] as u64) + carry;
+                t[j - 1] = v as u32;
+                carry = v >> 32;
+            }
+            let v = (t[LIMBS] as u64) + carry;
+            t[LIMBS - 1] = v as u32;
+            t[LIMBS] = t[LIMBS + 1] + (v >> 32) as u32;
+        }
+
+        let mut v = ZERO;
+        v.0.copy_from_slice(&t[..LIMBS]);
+        Scalar256::reduce_once(&v, t[LIMBS]).0
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0 == ZERO
+    }
+
+    pub fn add(&self, b: &Scalar256) -> Scalar256 {
+        let (v, carry) = self.0.add_no_reduce(&b.0);
+        Scalar256::reduce_once(&v, carry)
+    }
+
+    pub fn sub(&self, b: &Scalar256) -> Scalar256 {
+        let (v, carry_sub) = self.0.sub_no_reduce(&b.0);
+        // if self - b < 0, carry_sub == 1 and v == 2^256 + self - b
+        let (v2, _carry_add) = v.add_no_reduce(&N256);
+        Scalar256(Int256::choose(carry_sub != 0, &v, &v2))
+    }
+
+    pub fn mult(&self, b: &Scalar256) -> Scalar256 {
+        let ab = Scalar256::mont_mult(&self.0, &b.0);
+        Scalar256(Scalar256::mont_mult(&ab, &R2))
+    }
+
+    // return self^-1 = self^(N256 - 2)
+    pub fn inverse(&self) -> Scalar256 {
+        let (e, _) = N256.sub_no_reduce(&Int256([2, 0, 0, 0, 0, 0, 0, 0]));
+
+        // exponent is public, so square-and-multiply is fine here
+        let a = Scalar256::mont_mult(&self.0, &R2);
+        let mut y = a;
+        for i in (0..LIMBS).rev() {
+            for j in (0..32).rev() {
+                if i == LIMBS - 1 && j == 31 {
+                    // highest bit of N256 - 2 is set, it was consumed by `y = a`
+                    continue;
+                }
+                y = Scalar256::mont_mult(&y, &y);
+                if (e.0[i] >> j) & 1 == 1 {
+                    y = Scalar256::mont_mult(&y, &a);
+                }
+            }
+        }
+
+        Scalar256(Scalar256::mont_mult(&y, &ONE))
+    }
+
+    // big-endian.
+    pub fn to_bytes(self) -> [u8; 32] {
+        self.0.to_bytes()
+    }
+
+    // big-endian, returns None if value >= N256
+    pub fn from_bytes(b: &[u8; 32]) -> Option<Self> {
+        let v = Int256::from_bytes(b)?;
+        let (_, carry_sub) = v.sub_no_reduce(&N256);
+        if carry_sub == 0 {
+            return None;
+        }
+        Some(Scalar256(v))
+    }
+
+    // big-endian, value is reduced modulo N256
+    pub fn from_bytes_reduce(b: &[u8; 32]) -> Self {
+        let v = Int256::from_bytes(b).unwrap();
+        Scalar256::reduce_once(&v, 0)
+    }
+
+    // big-endian, value is reduced modulo N256
+    // 512-bit input makes the bias negligible for uniformly random bytes
+    pub fn from_wide_bytes(b: &[u8; 64]) -> Self {
+        let hi = Scalar256::from_bytes_reduce(b[..32].try_into().unwrap());
+        let lo = Scalar256::from_bytes_reduce(b[32..].try_into().unwrap());
+
+        // hi * 2^256 = mont_mult(hi, 2^512)
+        let hi = Scalar256(Scalar256::mont_mult(&hi.0, &R2));
+        hi.add(&lo)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Scalar256, N256, R2};
+    use crate::ec::p256::int256::{Int256, ONE, ZERO};
+
+    static VALUES_256: &[Scalar256] = &[
+        Scalar256(ONE),
+        Scalar256(Int256([2, 0, 0, 0, 0, 0, 0, 0])),
+        Scalar256(Int256([1; 8])),
+        Scalar256(Int256([1, 2, 3, 4, 5, 6, 7, 8])),
+        Scalar256(Int256([
+            0xfc632550, 0xf3b9cac2, 0xa7179e84, 0xbce6faad, //
+            0xffffffff, 0xffffffff, 0x00000000, 0xffffffff,
+        ])),
+        Scalar256(Int256([0xfffffffe, 0, 0, 0, 0xfffffffe, 0, 0, 0x7fffffff])),
+    ];
+
+    #[test]
+    fn test_scalar256_reduce() {
+        assert_eq!(Scalar256::from_bytes(&N256.to_bytes()), None);
+        assert!(Scalar256::from_bytes_reduce(&N256.to_bytes()).is_zero());
+        assert_eq!(
+            Scalar256::from_bytes_reduce(&[0xff; 32]),
+            Scalar256(Int256([
+                0x039cdaae, 0x0c46353d, 0x58e8617b, 0x43190552, //
+                0, 0, 0xffffffff, 0,
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_scalar256_from_wide_bytes() {
+        let mut b = [0u8; 64];
+        b.iter_mut().enumerate().for_each(|(i, x)| *x = i as u8);
+        assert_eq!(
+            Scalar256::from_wide_bytes(&b),
+            Scalar256(Int256([
+                0xae0c0caa, 0x9ad8e239, 0x1e0e7ad2, 0x77570a0c, //
+                0x4949750c, 0xf79e6d82, 0x87929169, 0x5fb87818,
+            ]))
+        );
+
+        // 2^512 - 1
+        let (r2_1, _) = R2.sub_no_reduce(&ONE);
+        assert_eq!(Scalar256::from_wide_bytes(&[0xff; 64]), Scalar256(r2_1));
+    }
+
+    #[test]
+    fn test_scalar256_sub() {
+        for a in VALUES_256.iter() {
+            assert!(a.sub(a).is_zero());
+
+            for b in VALUES_256.iter() {
+                assert_eq!(a.sub(b).add(b), *a);
+                assert_eq!(a.sub(b), Scalar256(ZERO).sub(&b.sub(a)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_scalar256_mult() {
+        for a in VALUES_256.iter() {
+            assert_eq!(a.mult(&Scalar256(ONE)), *a);
+            assert!(a.mult(&Scalar256(ZERO)).is_zero());
+
+            for b in VALUES_256.iter() {
+                let ab = a.mult(b);
+                assert_eq!(ab, b.mult(a));
+                for c in VALUES_256.iter() {
+                    assert_eq!(ab.mult(c), a.mult(&b.mult(c)));
+                    assert_eq!(a.mult(&b.add(c)), ab.add(&a.mult(c)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_scalar256_inverse() {
+        for a in VALUES_256.iter() {
+            let a_inv = a.inverse();
+            assert_eq!(a_inv.mult(a), Scalar256(ONE));
+            assert_eq!(a_inv.inverse(), *a);
+        }
+    }
+}
@@ -0,0 +1,216 @@
+// https://cr.yp.to/ecdh/curve25519-20060209.pdf
+// https://github.com/floodyberry/curve25519-donna
+
+const LIMBS: usize = 5;
+const MASK51: u64 = (1 << 51) - 1;
+
+// 2^51-radix: value = v[0] + 2^51 v[1] + ... + 2^204 v[4]
+// limbs may be slightly larger than 2^51, value may be >= P25519
+#[derive(Debug, Copy, Clone)]
+pub struct Fe25519(pub(crate) [u64; LIMBS]);
+
+pub const ZERO: Fe25519 = Fe25519([0; LIMBS]);
+pub const ONE: Fe25519 = Fe25519([1, 0, 0, 0, 0]);
+
+impl Fe25519 {
+    // propagate carries so every limb is < 2^51 + 2^13
+    fn carry(mut v: [u64; LIMBS]) -> Fe25519 {
+        for i in 0..LIMBS - 1 {
+            v[i + 1] += v[i] >> 51;
+            v[i] &= MASK51;
+        }
+        v[0] += (v[LIMBS - 1] >> 51) * 19;
+        v[LIMBS - 1] &= MASK51;
+        Fe25519(v)
+    }
+
+    pub fn add(&self, b: &Fe25519) -> Fe25519 {
+        let mut v = self.0;
+        v.iter_mut().zip(b.0).for_each(|(x, y)| *x += y);
+        Fe25519::carry(v)
+    }
+
+    pub fn sub(&self, b: &Fe25519) -> Fe25519 {
+        // add 2 * P25519 first, so every limb is positive
+        const P2: [u64; LIMBS] = [
+            0xfffffffffffda,
+            0xffffffffffffe,
+            0xffffffffffffe,
+            0xffffffffffffe,
+            0xffffffffffffe,
+        ];
+
+        let mut v = self.0;
+        for i in 0..LIMBS {
+            v[i] = v[i] + P2[i] - b.0[i];
+        }
+        Fe25519::carry(v)
+    }
+
+    pub fn mult(&self, b: &Fe25519) -> Fe25519 {
+        let [a0, a1, a2, a3, a4] = self.0.map(u128::from);
+        let [b0, b1, b2, b3, b4] = b.0.map(u128::from);
+        // 2^255 = 19 mod P25519
+        let [b1_19, b2_19, b3_19, b4_19] = [b1, b2, b3, b4].map(|x| x * 19);
+
+        let r0 = a0 * b0 + a1 * b4_19 + a2 * b3_19 + a3 * b2_19 + a4 * b1_19;
+        let mut r1 = a0 * b1 + a1 * b0 + a2 * b4_19 + a3 * b3_19 + a4 * b2_19;
+        let mut r2 = a0 * b2 + a1 * b1 + a2 * b0 + a3 * b4_19 + a4 * b3_19;
+        let mut r3 = a0 * b3 + a1 * b2 + a2 * b1 + a3 * b0 + a4 * b4_19;
+        let mut r4 = a0 * b4 + a1 * b3 + a2 * b2 + a3 * b1 + a4 * b0;
+
+        r1 += r0 >> 51;
+        r2 += r1 >> 51;
+        r3 += r2 >> 51;
+        r4 += r3 >> 51;
+
+        let mut v = [r0, r1, r2, r3, r4].map(|x| x as u64 & MASK51);
+        v[0] += (r4 >> 51) as u64 * 19;
+        v[1] += v[0] >> 51;
+        v[0] &= MASK51;
+
+        Fe25519(v)
+    }
+
+    pub fn square(&self) -> Fe25519 {
+        self.mult(self)
+    }
+
+    pub fn mult_small(&self, b: u32) -> Fe25519 {
+        let mut r = self.0.map(|x| (x as u128) * (b as u128));
+        for i in 0..LIMBS - 1 {
+            r[i + 1] += r[i] >> 51;
+        }
+
+        let mut v = r.map(|x| x as u64 & MASK51);
+        v[0] += (r[LIMBS - 1] >> 51) as u64 * 19;
+        v[1] += v[0] >> 51;
+        v[0] &= MASK51;
+
+        Fe25519(v)
+    }
+
+    // compute a^(2^n)
+    fn square_n(&self, n: usize) -> Fe25519 {
+        let mut y = *self;
+        for _ in 0..n {
+            y = y.square();
+        }
+        y
+    }
+
+    // return (self^(2^250 - 1), self^11)
+    fn pow_2_250_1(&self) -> (Fe25519, Fe25519) {
+        // z_n = self^(2^n - 1)
+        let z2 = self.square();
+        let z9 = z2.square_n(2).mult(self);
+        let z11 = z9.mult(&z2);
+        let z5 = z11.square().mult(&z9);
+        let z10 = z5.square_n(5).mult(&z5);
+        let z20 = z10.square_n(10).mult(&z10);
+        let z40 = z20.square_n(20).mult(&z20);
+        let z50 = z40.square_n(10).mult(&z10);
+        let z100 = z50.square_n(50).mult(&z50);
+        let z200 = z100.square_n(100).mult(&z100);
+        let z250 = z200.square_n(50).mult(&z50);
+
+        (z250, z11)
+    }
+
+    // return self^-1 = self^(P25519 - 2) = self^(2^255 - 21)
+    pub fn inverse(&self) -> Fe25519 {
+        let (z250, z11) = self.pow_2_250_1();
+
+        // 2^255 - 2^5 + 11
+        z250.square_n(5).mult(&z11)
+    }
+
+    // return self^((P25519 - 5) / 8) = self^(2^252 - 3)
+    pub fn pow22523(&self) -> Fe25519 {
+        let (z250, _) = self.pow_2_250_1();
+        z250.square_n(2).mult(self)
+    }
+
+    pub fn neg(&self) -> Fe25519 {
+        ZERO.sub(self)
+    }
+
+    // the lowest bit of the fully reduced value
+    pub fn is_negative(&self) -> bool {
+        self.to_bytes()[0] & 1 == 1
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.to_bytes() == [0u8; 32]
+    }
+
+    // constant time
+    // if flag == 0, returns a
+    // if flag == 1, returns b
+    pub fn choose(flag: bool, a: &Fe25519, b: &Fe25519) -> Fe25519 {
+        let mask = 0u64.wrapping_sub(flag as u64);
+        let mut v = a.0;
+        v.iter_mut()
+            .zip(b.0)
+            .for_each(|(x, y)| *x ^= mask & (*x ^ y));
+        Fe25519(v)
+    }
+
+    // constant time
+    // if flag == 1, swap a and b
+    pub fn swap(flag: bool, a: &mut Fe25519, b: &mut Fe25519) {
+        let mask = 0u64.wrapping_sub(flag as u64);
+        for i in 0..LIMBS {
+            let t = mask & (a.0[i] ^ b.0[i]);
+            a.0[i] ^= t;
+            b.0[i] ^= t;
+        }
+    }
+
+    // little-endian, fully reduced
+    pub fn to_bytes(self) -> [u8; 32] {
+        // now every limb is < 2^51, value is in [0, 2^255)
+        let mut v = Fe25519::carry(Fe25519::carry(self.0).0).0;
+
+        // value + 19, so values >= P25519 overflow 2^255
+        v[0] += 19;
+        v = Fe25519::carry(v).0;
+
+        // value + 2^255 - 19, then drop 2^255
+        v[0] += (1 << 51) - 19;
+        for x in &mut v[1..] {
+            *x += (1 << 51) - 1;
+        }
+        for i in 0..LIMBS - 1 {
+            v[i + 1] += v[i] >> 51;
+            v[i] &= MASK51;
+        }
+        v[LIMBS - 1] &= MASK51;
+
+        let words = [
+            v[0] | (v[1] << 51),
+            (v[1] >> 13) | (v[2] << 38),
+            (v[2] >> 26) | (v[3] << 25),
+            (v[3] >> 39) | (v[4] << 12),
+        ];
+
+        let mut r = [0u8; 32];
+        for (o, w) in r.as_chunks_mut().0.iter_mut().zip(words) {
+            *o = w.to_le_bytes();
+        }
+        r
+    }
+
+    // little-endian, the highest bit is ignored
+    pub fn from_bytes(b: &[u8; 32]) -> Fe25519 {
+        let load = |i: usize| u64::from_le_bytes(b[i..i + 8].try_into().unwrap());
+
+        Fe25519([
+            load(0) & MASK51,
+            (load(6) >> 3) & MASK51,
+            (load(12) >> 6) & MASK51,
+            (load(19) >> 1) & MASK51,
+            (load(24) >> 12) & MASK51,
+        ])
+    }
+}
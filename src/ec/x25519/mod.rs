@@ -0,0 +1,101 @@
+// RFC7748 https://tools.ietf.org/html/rfc7748
+
+use self::fe25519::{Fe25519, ONE, ZERO};
+use super::ECDH;
+use crate::{error::ECError, rng::Rng};
+
+pub(crate) mod fe25519;
+
+// u = 9
+pub const BASEPOINT: [u8; 32] = {
+    let mut b = [0u8; 32];
+    b[0] = 9;
+    b
+};
+
+// (A - 2) / 4
+const A24: u32 = 121665;
+
+fn clamp(k: &[u8; 32]) -> [u8; 32] {
+    let mut k = *k;
+    k[0] &= 248;
+    k[31] &= 127;
+    k[31] |= 64;
+    k
+}
+
+// Montgomery ladder, the sequence of field operations is independent of `k`.
+// `k` must be clamped.
+fn ladder(k: &[u8; 32], u: &[u8; 32]) -> [u8; 32] {
+    let x1 = Fe25519::from_bytes(u);
+    let (mut x2, mut z2) = (ONE, ZERO);
+    let (mut x3, mut z3) = (x1, ONE);
+    let mut swap = false;
+
+    for t in (0..255).rev() {
+        let bit = (k[t / 8] >> (t % 8)) & 1 == 1;
+        swap ^= bit;
+        Fe25519::swap(swap, &mut x2, &mut x3);
+        Fe25519::swap(swap, &mut z2, &mut z3);
+        swap = bit;
+
+        let a = x2.add(&z2);
+        let aa = a.square();
+        let b = x2.sub(&z2);
+        let bb = b.square();
+        let e = aa.sub(&bb);
+        let c = x3.add(&z3);
+        let d = x3.sub(&z3);
+        let da = d.mult(&a);
+        let cb = c.mult(&b);
+
+        x3 = da.add(&cb).square();
+        z3 = x1.mult(&da.sub(&cb).square());
+        x2 = aa.mult(&bb);
+        z2 = e.mult(&aa.add(&e.mult_small(A24)));
+    }
+
+    Fe25519::swap(swap, &mut x2, &mut x3);
+    Fe25519::swap(swap, &mut z2, &mut z3);
+
+    x2.mult(&z2.inverse()).to_bytes()
+}
+
+// the X25519 function in RFC7748, scalar `k` is clamped before use
+pub fn x25519(k: &[u8; 32], u: &[u8; 32]) -> [u8; 32] {
+    ladder(&clamp(k), u)
+}
+
+#[derive(Debug)]
+pub struct X25519 {
+    x: [u8; 32],
+}
+
+impl ECDH for X25519 {
+    const POINT_SIZE: usize = 32;
+    const INT_SIZE: usize = 32;
+
+    fn new(x: &[u8; Self::INT_SIZE]) -> Self {
+        Self { x: clamp(x) }
+    }
+
+    fn generate(rng: &mut impl Rng) -> Self {
+        let mut buf = [0u8; 32];
+        rng.fill_bytes(&mut buf);
+        Self::new(&buf)
+    }
+
+    fn to_public(&self) -> [u8; 32] {
+        ladder(&self.x, &BASEPOINT)
+    }
+
+    fn exchange(&self, gy: &[u8; 32]) -> Result<[u8; 32], ECError> {
+        let gxy = ladder(&self.x, gy);
+
+        // low order point results in all-zero shared secret
+        if gxy == [0u8; 32] {
+            return Err(ECError::InvalidPublicKey);
+        }
+        Ok(gxy)
+    }
+}
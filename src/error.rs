@@ -18,12 +18,14 @@ impl fmt::Display for HexCodecError {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ECError {
     InvalidPublicKey,
+    InvalidSignature,
 }
 
 impl fmt::Display for ECError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             Self::InvalidPublicKey => f.write_str("Invalid public key"),
+            Self::InvalidSignature => f.write_str("Invalid signature"),
         }
     }
 }
@@ -32,6 +34,7 @@ impl fmt::Display for ECError {
 pub enum AeadError {
     BadMac,
     InvalidBlockSize,
+    InvalidLength,
 }
 
 impl fmt::Display for AeadError {
@@ -39,6 +42,56 @@ impl fmt::Display for AeadError {
         match *self {
             Self::BadMac => f.write_str("Invalid Mac"),
             Self::InvalidBlockSize => f.write_str("Invalid Block Size"),
+            Self::InvalidLength => f.write_str("Invalid Length"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HashError {
+    CollisionDetected,
+}
+
+impl fmt::Display for HashError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Self::CollisionDetected => f.write_str("Collision attack detected"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KdfError {
+    InvalidLength,
+    InvalidIterationCount,
+}
+
+impl fmt::Display for KdfError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Self::InvalidLength => f.write_str("Invalid length"),
+            Self::InvalidIterationCount => f.write_str("Invalid iteration count"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CipherError {
+    InvalidLength,
+    InvalidPadding,
+    IntegrityCheckFailed,
+    CounterOverflow,
+    InvalidKey,
+}
+
+impl fmt::Display for CipherError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Self::InvalidLength => f.write_str("Invalid length"),
+            Self::InvalidPadding => f.write_str("Invalid padding"),
+            Self::IntegrityCheckFailed => f.write_str("Integrity check failed"),
+            Self::CounterOverflow => f.write_str("Counter overflow"),
+            Self::InvalidKey => f.write_str("Invalid key"),
         }
     }
 }
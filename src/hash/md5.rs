@@ -29,7 +29,7 @@ const K: [u32; 64] = [
 
 const INIT_VECTOR: [u32; STATE_SIZE] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476];
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct MD5 {
     count: u64,
     state: [u32; STATE_SIZE],
@@ -53,10 +53,7 @@ impl Digest for MD5 {
     }
 
     fn digest(mut self, remainder: &[u8]) -> [u8; Self::DIGEST_LENGTH] {
-        let (aligned_blocks, remainder) = remainder.as_chunks();
-        for block in aligned_blocks {
-            self.update(block);
-        }
+        let remainder = self.update_blocks(remainder);
 
         let mut buffer = [0u8; Self::BLOCK_LENGTH];
         buffer[..remainder.len()].copy_from_slice(remainder);
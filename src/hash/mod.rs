@@ -1,88 +1,92 @@
 pub mod md5;
+pub mod sha1;
 pub mod sha256;
+pub mod sha384;
+pub mod sha512;
 
 // Merkle-Damgård structure
 
-// use crate::util::endian::EndianConvertion;
-
-pub trait Digest {
+pub trait Digest: Clone {
     const BLOCK_LENGTH: usize;
     const DIGEST_LENGTH: usize;
 
     fn new() -> Self;
     fn update(&mut self, data: &[u8; Self::BLOCK_LENGTH]);
     fn digest(self, remainder: &[u8]) -> [u8; Self::DIGEST_LENGTH];
-}
 
-// pub(crate) fn general_update<E, const STATE_SIZE: usize, const COMPRESS_SIZE: usize>(
-//     count: &mut u64,
-//     state: &mut [u32; STATE_SIZE],
-//     buffer: &mut [u8; COMPRESS_SIZE],
-//     buffer_offset: &mut usize,
-//     compress: fn(&mut [u32; STATE_SIZE], &[u8; COMPRESS_SIZE]),
-//     data: &[u8],
-// ) where
-//     E: EndianConvertion,
-// {
-//     let data_length = data.len();
-
-//     // process previous block
-//     if *buffer_offset + data_length < COMPRESS_SIZE {
-//         buffer[*buffer_offset..*buffer_offset + data_length].copy_from_slice(data);
-//         *buffer_offset += data_length;
-//         *count += data_length as u64;
-//         return;
-//     }
+    // process all complete blocks of `data`, return the unprocessed remainder
+    fn update_blocks<'a>(&mut self, data: &'a [u8]) -> &'a [u8]
+    where
+        [u8; Self::BLOCK_LENGTH]: Sized,
+    {
+        let blocks = data.chunks_exact(Self::BLOCK_LENGTH);
+        let remainder = blocks.remainder();
+        for block in blocks {
+            self.update(block.try_into().unwrap());
+        }
+        remainder
+    }
+}
 
-//     // compress buffer
-//     buffer[*buffer_offset..].copy_from_slice(&data[..COMPRESS_SIZE - *buffer_offset]);
-//     compress(state, buffer);
-//     *count += COMPRESS_SIZE as u64;
+// buffered streaming hasher, accepts arbitrary-sized input
+#[derive(Debug, Clone)]
+pub struct Hasher<D: Digest>
+where
+    [u8; D::BLOCK_LENGTH]: Sized,
+{
+    digest: D,
+    buffer: [u8; D::BLOCK_LENGTH],
+    buffer_offset: usize,
+}
 
-//     // process current blocks
-//     let (chunks, remain) = data[COMPRESS_SIZE - *buffer_offset..].as_chunks();
-//     for chunk in chunks {
-//         compress(state, chunk);
-//     }
-//     *count += COMPRESS_SIZE as u64 * chunks.len() as u64;
+impl<D: Digest> Hasher<D>
+where
+    [u8; D::BLOCK_LENGTH]: Sized,
+{
+    pub fn new() -> Self {
+        Self {
+            digest: D::new(),
+            buffer: [0u8; D::BLOCK_LENGTH],
+            buffer_offset: 0,
+        }
+    }
 
-//     // move remainder to buffer
-//     buffer[..remain.len()].copy_from_slice(remain);
-//     *buffer_offset = remain.len();
-//     *count += remain.len() as u64;
-// }
+    pub fn update(&mut self, data: &[u8]) {
+        let Self {
+            digest,
+            buffer,
+            buffer_offset,
+        } = self;
 
-// pub(crate) fn general_digest<
-//     E,
-//     const STATE_SIZE: usize,
-//     const COMPRESS_SIZE: usize,
-//     const LENGTH: usize,
-// >(
-//     count: u64,
-//     mut state: [u32; STATE_SIZE],
-//     mut buffer: [u8; COMPRESS_SIZE],
-//     buffer_offset: usize,
-//     compress: fn(&mut [u32; STATE_SIZE], &[u8; COMPRESS_SIZE]),
-// ) -> [u8; LENGTH]
-// where
-//     E: EndianConvertion,
-// {
-//     let mut result = [0u8; LENGTH];
+        // fill previous block
+        if *buffer_offset + data.len() < D::BLOCK_LENGTH {
+            buffer[*buffer_offset..*buffer_offset + data.len()].copy_from_slice(data);
+            *buffer_offset += data.len();
+            return;
+        }
 
-//     // padding
-//     buffer[buffer_offset] = 0x80;
-//     buffer[buffer_offset + 1..].fill(0);
+        let (head, data) = data.split_at(D::BLOCK_LENGTH - *buffer_offset);
+        buffer[*buffer_offset..].copy_from_slice(head);
+        digest.update(buffer);
 
-//     if buffer_offset >= COMPRESS_SIZE - 8 {
-//         // not enough space for bit size
-//         compress(&mut state, &buffer);
-//         buffer.fill(0);
-//     }
+        // process current blocks
+        let remainder = digest.update_blocks(data);
 
-//     buffer[COMPRESS_SIZE - 8..].copy_from_slice(&(count * 8).to_be_bytes());
+        // move remainder to buffer
+        buffer[..remainder.len()].copy_from_slice(remainder);
+        *buffer_offset = remainder.len();
+    }
 
-//     compress(&mut state, &buffer);
+    pub fn finalize(self) -> [u8; D::DIGEST_LENGTH] {
+        self.digest.digest(&self.buffer[..self.buffer_offset])
+    }
+}
 
-//     E::to_bytes(&mut result, &state);
-//     result
-// }
+impl<D: Digest> Default for Hasher<D>
+where
+    [u8; D::BLOCK_LENGTH]: Sized,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
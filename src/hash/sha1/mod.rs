@@ -0,0 +1,269 @@
+// RFC3174 https://tools.ietf.org/html/rfc3174
+//
+// WARNING: SHA-1 is broken, chosen-prefix collisions are practical.
+// Only use it to interoperate with legacy protocols (Git object IDs, TOTP, HMAC-SHA1),
+// never for new signatures or content addressing of untrusted data.
+// `SHA1CD` detects messages crafted with the known collision attacks.
+
+use core::mem::size_of;
+
+use self::dv::DVS;
+use super::Digest;
+use crate::{
+    error::HashError,
+    utils::endian::{assert_len_mut, BigEndian, EndianConvertion},
+};
+
+mod dv;
+
+pub const STATE_SIZE: usize = 5;
+pub const COMPRESS_SIZE: usize = 64;
+pub const COUNTER_SIZE: usize = size_of::<u64>();
+pub const DIGEST_SIZE: usize = 20;
+
+const INIT_VECTOR: [u32; STATE_SIZE] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0];
+
+#[derive(Debug, Clone)]
+pub struct SHA1 {
+    count: u64,
+    state: [u32; STATE_SIZE],
+}
+
+impl Digest for SHA1 {
+    const BLOCK_LENGTH: usize = COMPRESS_SIZE;
+    const DIGEST_LENGTH: usize = DIGEST_SIZE;
+
+    fn new() -> Self {
+        Self {
+            count: 0,
+            state: INIT_VECTOR,
+        }
+    }
+
+    fn update(&mut self, data: &[u8; Self::BLOCK_LENGTH]) {
+        let Self { count, state } = self;
+        compress(state, &expand(data));
+        *count += data.len() as u64;
+    }
+
+    fn digest(mut self, remainder: &[u8]) -> [u8; Self::DIGEST_LENGTH] {
+        let remainder = self.update_blocks(remainder);
+
+        let Self { count, mut state } = self;
+        padding(count, remainder, |block: &[u8; COMPRESS_SIZE]| {
+            compress(&mut state, &expand(block))
+        });
+
+        let mut result = [0u8; Self::DIGEST_LENGTH];
+        BigEndian::to_bytes(&mut result, &state);
+        result
+    }
+}
+
+// SHA-1 with collision detection, see https://marc-stevens.nl/research/papers/C13-S.pdf
+// When a collision attack is detected, the digest is replaced by a "safe hash",
+// same as Git does. Use `digest_checked` to get an error instead.
+// Every block is recompressed for all 32 disturbance vectors (the unavoidable
+// bitcondition filter of sha1collisiondetection is not implemented), so it is
+// about 25x slower than `SHA1`.
+#[derive(Debug, Clone)]
+pub struct SHA1CD {
+    count: u64,
+    state: [u32; STATE_SIZE],
+    found_collision: bool,
+}
+
+impl SHA1CD {
+    pub fn found_collision(&self) -> bool {
+        self.found_collision
+    }
+
+    pub fn digest_checked(mut self, remainder: &[u8]) -> Result<[u8; DIGEST_SIZE], HashError> {
+        let remainder = self.update_blocks(remainder);
+
+        let Self {
+            count,
+            mut state,
+            mut found_collision,
+        } = self;
+        padding(count, remainder, |block: &[u8; COMPRESS_SIZE]| {
+            found_collision |= compress_detect(&mut state, block)
+        });
+
+        if found_collision {
+            return Err(HashError::CollisionDetected);
+        }
+
+        let mut result = [0u8; DIGEST_SIZE];
+        BigEndian::to_bytes(&mut result, &state);
+        Ok(result)
+    }
+}
+
+impl Digest for SHA1CD {
+    const BLOCK_LENGTH: usize = COMPRESS_SIZE;
+    const DIGEST_LENGTH: usize = DIGEST_SIZE;
+
+    fn new() -> Self {
+        Self {
+            count: 0,
+            state: INIT_VECTOR,
+            found_collision: false,
+        }
+    }
+
+    fn update(&mut self, data: &[u8; Self::BLOCK_LENGTH]) {
+        let Self {
+            count,
+            state,
+            found_collision,
+        } = self;
+        *found_collision |= compress_detect(state, data);
+        *count += data.len() as u64;
+    }
+
+    fn digest(mut self, remainder: &[u8]) -> [u8; Self::DIGEST_LENGTH] {
+        let remainder = self.update_blocks(remainder);
+
+        let Self {
+            count, mut state, ..
+        } = self;
+        padding(count, remainder, |block: &[u8; COMPRESS_SIZE]| {
+            compress_detect(&mut state, block);
+        });
+
+        let mut result = [0u8; Self::DIGEST_LENGTH];
+        BigEndian::to_bytes(&mut result, &state);
+        result
+    }
+}
+
+// `count` doesn't include `remainder`
+fn padding(count: u64, remainder: &[u8], mut compress: impl FnMut(&[u8; COMPRESS_SIZE])) {
+    let mut buffer = [0u8; COMPRESS_SIZE];
+    buffer[..remainder.len()].copy_from_slice(remainder);
+
+    let count = count + remainder.len() as u64;
+
+    buffer[remainder.len()] = 0x80;
+
+    if remainder.len() >= COMPRESS_SIZE - COUNTER_SIZE {
+        // not enough space for bit size
+        compress(&buffer);
+        buffer.fill(0);
+    }
+
+    buffer[COMPRESS_SIZE - COUNTER_SIZE..].copy_from_slice(&(count * 8).to_be_bytes());
+
+    compress(&buffer);
+}
+
+fn expand(data: &[u8; COMPRESS_SIZE]) -> [u32; 80] {
+    let mut w = [0u32; 80];
+
+    BigEndian::from_bytes(assert_len_mut::<16, _>(&mut w[..16]), data);
+
+    for j in 16..80 {
+        w[j] = (w[j - 3] ^ w[j - 8] ^ w[j - 14] ^ w[j - 16]).rotate_left(1);
+    }
+
+    w
+}
+
+// f_j(b, c, d) + K_j
+fn f(j: usize, b: u32, c: u32, d: u32) -> u32 {
+    match j {
+        0..=19 => ((b & c) | (!b & d)).wrapping_add(0x5a827999),
+        20..=39 => (b ^ c ^ d).wrapping_add(0x6ed9eba1),
+        40..=59 => ((b & c) | (b & d) | (c & d)).wrapping_add(0x8f1bbcdc),
+        _ => (b ^ c ^ d).wrapping_add(0xca62c1d6),
+    }
+}
+
+fn step(j: usize, [a, b, c, d, e]: [u32; STATE_SIZE], w: u32) -> [u32; STATE_SIZE] {
+    let t = a
+        .rotate_left(5)
+        .wrapping_add(f(j, b, c, d))
+        .wrapping_add(e)
+        .wrapping_add(w);
+    [t, a, b.rotate_left(30), c, d]
+}
+
+// inverse of `step`
+fn step_back(j: usize, [a, b, c, d, e]: [u32; STATE_SIZE], w: u32) -> [u32; STATE_SIZE] {
+    let (a0, b0, c0, d0) = (b, c.rotate_right(30), d, e);
+    let e0 = a
+        .wrapping_sub(a0.rotate_left(5))
+        .wrapping_sub(f(j, b0, c0, d0))
+        .wrapping_sub(w);
+    [a0, b0, c0, d0, e0]
+}
+
+fn feed_forward(state: &mut [u32; STATE_SIZE], s: [u32; STATE_SIZE]) {
+    state
+        .iter_mut()
+        .zip(s)
+        .for_each(|(v, x)| *v = v.wrapping_add(x));
+}
+
+fn compress(state: &mut [u32; STATE_SIZE], w: &[u32; 80]) {
+    let mut s = *state;
+    for (j, &wj) in w.iter().enumerate() {
+        s = step(j, s, wj);
+    }
+    feed_forward(state, s);
+}
+
+// return true if the block is a near-collision block of a known attack
+fn compress_detect(state: &mut [u32; STATE_SIZE], data: &[u8; COMPRESS_SIZE]) -> bool {
+    let w = expand(data);
+
+    // states before step 58 and 65, where recompressions start
+    let mut saved = [[0u32; STATE_SIZE]; 2];
+    let mut s = *state;
+    for (j, &wj) in w.iter().enumerate() {
+        match j {
+            58 => saved[0] = s,
+            65 => saved[1] = s,
+            _ => {}
+        }
+        s = step(j, s, wj);
+    }
+    feed_forward(state, s);
+
+    for dv in DVS.iter() {
+        let mut w2 = w;
+        w2.iter_mut().zip(dv.dm).for_each(|(x, y)| *x ^= y);
+
+        let t = dv.test_step;
+        let start = saved[(t == 65) as usize];
+
+        // compute the chaining value and the output of the other block from step `t`
+        let mut ihv = start;
+        for j in (0..t).rev() {
+            ihv = step_back(j, ihv, w2[j]);
+        }
+        let mut s = start;
+        for (j, &wj) in w2.iter().enumerate().skip(t) {
+            s = step(j, s, wj);
+        }
+        feed_forward(&mut ihv, s);
+
+        if ihv == *state {
+            // safe hash: makes the colliding messages hash differently
+            compress(state, &w);
+            compress(state, &w);
+            return true;
+        }
+    }
+
+    false
+}
+
+pub fn sha1(data: &[u8]) -> [u8; SHA1::DIGEST_LENGTH] {
+    SHA1::new().digest(data)
+}
+
+pub fn sha1_checked(data: &[u8]) -> Result<[u8; DIGEST_SIZE], HashError> {
+    SHA1CD::new().digest_checked(data)
+}
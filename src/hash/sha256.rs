@@ -24,7 +24,7 @@ const INIT_VECTOR: [u32; STATE_SIZE] = [
     0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
 ];
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SHA256 {
     count: u64,
     state: [u32; STATE_SIZE],
@@ -48,10 +48,7 @@ impl Digest for SHA256 {
     }
 
     fn digest(mut self, remainder: &[u8]) -> [u8; Self::DIGEST_LENGTH] {
-        let (aligned_blocks, remainder) = remainder.as_chunks();
-        for block in aligned_blocks {
-            self.update(block);
-        }
+        let remainder = self.update_blocks(remainder);
 
         let mut buffer = [0u8; Self::BLOCK_LENGTH];
         buffer[..remainder.len()].copy_from_slice(remainder);
@@ -0,0 +1,44 @@
+// http://csrc.nist.gov/groups/STM/cavp/documents/shs/sha256-384-512.pdf
+
+use super::{
+    sha512::{COMPRESS_SIZE, SHA512, STATE_SIZE},
+    Digest,
+};
+
+const INIT_VECTOR: [u64; STATE_SIZE] = [
+    0xcbbb9d5dc1059ed8,
+    0x629a292a367cd507,
+    0x9159015a3070dd17,
+    0x152fecd8f70e5939,
+    0x67332667ffc00b31,
+    0x8eb44a8768581511,
+    0xdb0c2e0d64f98fa7,
+    0x47b5481dbefa4fa4,
+];
+
+// SHA512 with different initial state, truncated to 384 bits
+#[derive(Debug, Clone)]
+pub struct SHA384(SHA512);
+
+impl Digest for SHA384 {
+    const BLOCK_LENGTH: usize = COMPRESS_SIZE;
+    const DIGEST_LENGTH: usize = 48;
+
+    fn new() -> Self {
+        Self(SHA512::with_state(INIT_VECTOR))
+    }
+
+    fn update(&mut self, data: &[u8; Self::BLOCK_LENGTH]) {
+        self.0.update(data);
+    }
+
+    fn digest(self, remainder: &[u8]) -> [u8; Self::DIGEST_LENGTH] {
+        let mut result = [0u8; Self::DIGEST_LENGTH];
+        result.copy_from_slice(&self.0.digest(remainder)[..Self::DIGEST_LENGTH]);
+        result
+    }
+}
+
+pub fn sha384(data: &[u8]) -> [u8; SHA384::DIGEST_LENGTH] {
+    SHA384::new().digest(data)
+}
@@ -0,0 +1,83 @@
+// RFC5869 https://tools.ietf.org/html/rfc5869
+
+use crate::{error::KdfError, hash::Digest, mac::hmac::Hmac};
+
+// PRK = HMAC-Hash(salt, IKM)
+// empty salt is the same as HashLen zeros
+pub fn extract<H: Digest>(salt: &[u8], ikm: &[u8]) -> [u8; H::DIGEST_LENGTH]
+where
+    [u8; H::BLOCK_LENGTH]: Sized,
+    [u8; H::DIGEST_LENGTH]: Sized,
+{
+    let mut h = Hmac::<H>::new_from_slice(salt);
+    h.update_slice(ikm);
+    h.digest()
+}
+
+// fill `okm` with T(1) | T(2) | ..., okm.len() must be <= 255 * HashLen
+pub fn expand<H: Digest>(prk: &[u8], info: &[u8], okm: &mut [u8]) -> Result<(), KdfError>
+where
+    [u8; H::BLOCK_LENGTH]: Sized,
+    [u8; H::DIGEST_LENGTH]: Sized,
+{
+    if okm.len() > 255 * H::DIGEST_LENGTH {
+        return Err(KdfError::InvalidLength);
+    }
+
+    let keyed = Hmac::<H>::new_from_slice(prk);
+    let mut t = [0u8; H::DIGEST_LENGTH];
+    for (i, chunk) in okm.chunks_mut(H::DIGEST_LENGTH).enumerate() {
+        // T(i) = HMAC-Hash(PRK, T(i - 1) | info | i)
+        let mut h = keyed.clone();
+        if i != 0 {
+            h.update_slice(&t);
+        }
+        h.update_slice(info);
+        h.update_slice(&[i as u8 + 1]);
+        t = h.digest();
+
+        chunk.copy_from_slice(&t[..chunk.len()]);
+    }
+
+    Ok(())
+}
+
+// RFC8446 7.1 HKDF-Expand-Label, `label` is without the "tls13 " prefix
+pub fn expand_label<H: Digest>(
+    secret: &[u8],
+    label: &[u8],
+    context: &[u8],
+    okm: &mut [u8],
+) -> Result<(), KdfError>
+where
+    [u8; H::BLOCK_LENGTH]: Sized,
+    [u8; H::DIGEST_LENGTH]: Sized,
+{
+    const PREFIX: &[u8] = b"tls13 ";
+
+    // struct {
+    //     uint16 length;
+    //     opaque label<7..255>;
+    //     opaque context<0..255>;
+    // } HkdfLabel;
+    let label_length = PREFIX.len() + label.len();
+    if okm.len() > u16::MAX as usize || label_length > 255 || context.len() > 255 {
+        return Err(KdfError::InvalidLength);
+    }
+
+    let mut info = [0u8; 2 + 1 + 255 + 1 + 255];
+    let mut offset = 0;
+    for part in [
+        &(okm.len() as u16).to_be_bytes()[..],
+        &[label_length as u8],
+        PREFIX,
+        label,
+        &[context.len() as u8],
+        context,
+    ] {
+        info[offset..offset + part.len()].copy_from_slice(part);
+        offset += part.len();
+    }
+
+    expand::<H>(secret, &info[..offset], okm)
+}
@@ -0,0 +1,2 @@
+pub mod hkdf;
+pub mod pbkdf2;
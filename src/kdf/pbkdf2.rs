@@ -0,0 +1,44 @@
+// RFC8018 https://tools.ietf.org/html/rfc8018#section-5.2
+
+use crate::{error::KdfError, hash::Digest, mac::hmac::Hmac, utils::xor::xor_static};
+
+// PBKDF2 with HMAC-Hash as PRF, fill the whole `output` as derived key
+pub fn pbkdf2<H: Digest>(
+    password: &[u8],
+    salt: &[u8],
+    rounds: u32,
+    output: &mut [u8],
+) -> Result<(), KdfError>
+where
+    [u8; H::BLOCK_LENGTH]: Sized,
+    [u8; H::DIGEST_LENGTH]: Sized,
+{
+    if rounds == 0 {
+        return Err(KdfError::InvalidIterationCount);
+    }
+    if output.len() as u64 > u32::MAX as u64 * H::DIGEST_LENGTH as u64 {
+        return Err(KdfError::InvalidLength);
+    }
+
+    let keyed = Hmac::<H>::new_from_slice(password);
+    for (i, chunk) in output.chunks_mut(H::DIGEST_LENGTH).enumerate() {
+        // U_1 = PRF(P, S || INT(i))
+        let mut h = keyed.clone();
+        h.update_slice(salt);
+        h.update_slice(&(i as u32 + 1).to_be_bytes());
+        let mut u = h.digest();
+
+        // T_i = U_1 ^ U_2 ^ ... ^ U_c
+        let mut t = u;
+        for _ in 1..rounds {
+            let mut h = keyed.clone();
+            h.update_slice(&u);
+            u = h.digest();
+            xor_static(&mut t, &u);
+        }
+
+        chunk.copy_from_slice(&t[..chunk.len()]);
+    }
+
+    Ok(())
+}
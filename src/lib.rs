@@ -8,6 +8,7 @@ pub mod aead;
 pub mod ec;
 pub mod error;
 pub mod hash;
+pub mod kdf;
 pub mod mac;
 pub mod rng;
 pub mod symmetry;
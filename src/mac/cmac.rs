@@ -0,0 +1,123 @@
+// RFC4493 https://tools.ietf.org/html/rfc4493
+// NIST SP800-38B https://nvlpubs.nist.gov/nistpubs/SpecialPublications/NIST.SP.800-38B.pdf
+
+use super::Mac;
+use crate::{
+    symmetry::{
+        aes::{Aes128, Aes192, Aes256},
+        BlockCipher,
+    },
+    utils::xor::xor_static,
+};
+
+#[derive(Debug, Clone)]
+pub struct Cmac<C> {
+    cipher: C,
+    k1: [u8; 16],
+    k2: [u8; 16],
+    state: [u8; 16],
+    // the last block is held back until the end, it is masked with a subkey
+    buffer: [u8; 16],
+    buffer_offset: usize,
+}
+
+pub type AesCmac = Cmac<Aes128>;
+
+// multiply by x in GF(2^128), big-endian
+pub(crate) fn dbl(block: &[u8; 16]) -> [u8; 16] {
+    let b = u128::from_be_bytes(*block);
+    let carry = (b >> 127) as u8;
+    let mut r = (b << 1).to_be_bytes();
+    r[15] ^= carry.wrapping_neg() & 0x87;
+    r
+}
+
+impl<C: BlockCipher<16>> Cmac<C> {
+    // RFC4493 2.3, subkey generation
+    pub fn from_cipher(cipher: C) -> Self {
+        let mut l = [0u8; 16];
+        cipher.encrypt(&mut l);
+        let k1 = dbl(&l);
+        let k2 = dbl(&k1);
+
+        Self {
+            cipher,
+            k1,
+            k2,
+            state: [0u8; 16],
+            buffer: [0u8; 16],
+            buffer_offset: 0,
+        }
+    }
+
+    // arbitrary-sized input
+    pub fn update_slice(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            if self.buffer_offset == 16 {
+                xor_static(&mut self.state, &self.buffer);
+                self.cipher.encrypt(&mut self.state);
+                self.buffer_offset = 0;
+            }
+
+            let n = data.len().min(16 - self.buffer_offset);
+            self.buffer[self.buffer_offset..self.buffer_offset + n].copy_from_slice(&data[..n]);
+            self.buffer_offset += n;
+            data = &data[n..];
+        }
+    }
+
+    pub fn digest(self) -> [u8; 16] {
+        let Self {
+            cipher,
+            k1,
+            k2,
+            mut state,
+            mut buffer,
+            buffer_offset,
+        } = self;
+
+        if buffer_offset == 16 {
+            xor_static(&mut buffer, &k1);
+        } else {
+            buffer[buffer_offset] = 0x80;
+            buffer[buffer_offset + 1..].fill(0);
+            xor_static(&mut buffer, &k2);
+        }
+
+        xor_static(&mut state, &buffer);
+        cipher.encrypt(&mut state);
+        state
+    }
+}
+
+// see `impl_mac` in hmac.rs
+macro_rules! impl_mac {
+    ($($c:ty),*) => {$(
+        impl Mac for Cmac<$c> {
+            const KEY_LENGTH: usize = <$c as BlockCipher<16>>::KEY_LENGTH;
+            const BLOCK_LENGTH: usize = 16;
+            const MAC_LENGTH: usize = 16;
+
+            fn new(key: &[u8; Self::KEY_LENGTH]) -> Self {
+                Self::from_cipher(<$c>::new(key))
+            }
+
+            fn update(&mut self, data: &[u8; Self::BLOCK_LENGTH]) {
+                self.update_slice(data);
+            }
+
+            fn finalize(mut self, remainder: &[u8]) -> [u8; Self::MAC_LENGTH] {
+                self.update_slice(remainder);
+                self.digest()
+            }
+        }
+    )*};
+}
+
+impl_mac!(Aes128, Aes192, Aes256);
+
+pub fn cmac<C: BlockCipher<16>>(cipher: C, message: &[u8]) -> [u8; 16] {
+    let mut c = Cmac::from_cipher(cipher);
+    c.update_slice(message);
+    c.digest()
+}
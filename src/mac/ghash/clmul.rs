@@ -47,7 +47,7 @@ impl Mac for GHash {
             buffer[..remainder.len()].copy_from_slice(remainder);
             compress(&mut self, &buffer);
         }
-        let mut output = self.core.finalize();
+        let mut output = self.core.finalize(&[]);
         output.reverse();
         output
     }
@@ -60,12 +60,18 @@ fn compress(state: &mut GHash, data: &[u8; GHash::BLOCK_LENGTH]) {
     state.core.update(&x);
 }
 
-struct Polyval {
+// RFC8452 3
+#[derive(Debug, Clone)]
+pub struct Polyval {
     h: __m128i,
     s: __m128i,
 }
 
-impl Polyval {
+impl Mac for Polyval {
+    const KEY_LENGTH: usize = 16;
+    const BLOCK_LENGTH: usize = 16;
+    const MAC_LENGTH: usize = 16;
+
     fn new(h: &[u8; 16]) -> Self {
         unsafe {
             Self {
@@ -75,7 +81,7 @@ impl Polyval {
         }
     }
 
-    fn update(&mut self, x: &[u8; 16]) {
+    fn update(&mut self, x: &[u8; Self::BLOCK_LENGTH]) {
         unsafe {
             let h = self.h;
 
@@ -132,7 +138,19 @@ impl Polyval {
         }
     }
 
-    fn finalize(self) -> [u8; 16] {
+    // a partial block is zero padded
+    fn finalize(mut self, remainder: &[u8]) -> [u8; 16] {
+        let (aligned_blocks, remainder) = remainder.as_chunks();
+        for block in aligned_blocks {
+            self.update(block);
+        }
+
+        if !remainder.is_empty() {
+            let mut buffer = [0u8; Self::BLOCK_LENGTH];
+            buffer[..remainder.len()].copy_from_slice(remainder);
+            self.update(&buffer);
+        }
+
         let mut block = [0u8; 16];
 
         unsafe { _mm_storeu_si128(block.as_mut_ptr().cast(), self.s) };
@@ -48,7 +48,7 @@ impl Mac for GHash {
             buffer[..remainder.len()].copy_from_slice(remainder);
             compress(&mut self, &buffer);
         }
-        let mut output = self.core.finalize();
+        let mut output = self.core.finalize(&[]);
         output.reverse();
         output
     }
@@ -61,27 +61,43 @@ fn compress(state: &mut GHash, data: &[u8; GHash::BLOCK_LENGTH]) {
     state.core.update(&x);
 }
 
-struct Polyval {
+// RFC8452 3
+#[derive(Debug, Clone)]
+pub struct Polyval {
     h: U32x4,
     s: U32x4,
 }
 
-impl Polyval {
+impl Mac for Polyval {
+    const KEY_LENGTH: usize = 16;
+    const BLOCK_LENGTH: usize = 16;
+    const MAC_LENGTH: usize = 16;
+
     fn new(h: &[u8; 16]) -> Self {
         Self {
             h: h.into(),
             s: U32x4::default(),
         }
     }
-}
 
-impl Polyval {
-    fn update(&mut self, x: &[u8; 16]) {
+    fn update(&mut self, x: &[u8; Self::BLOCK_LENGTH]) {
         let x = U32x4::from(x);
         self.s = (self.s + x) * self.h;
     }
 
-    fn finalize(self) -> [u8; 16] {
+    // a partial block is zero padded
+    fn finalize(mut self, remainder: &[u8]) -> [u8; 16] {
+        let (aligned_blocks, remainder) = remainder.as_chunks();
+        for block in aligned_blocks {
+            self.update(block);
+        }
+
+        if !remainder.is_empty() {
+            let mut buffer = [0u8; Self::BLOCK_LENGTH];
+            buffer[..remainder.len()].copy_from_slice(remainder);
+            self.update(&buffer);
+        }
+
         let mut block = [0u8; 16];
 
         LittleEndian::to_bytes(&mut block, &[self.s.0, self.s.1, self.s.2, self.s.3]);
@@ -1,41 +1,97 @@
-use crate::{hash::Digest, utils::xor::xor_static};
+// RFC2104 https://tools.ietf.org/html/rfc2104
 
-// Rust trait can not handle assosiated const in generic
-// so hmac function returns two array:
-// 1. the first array is the hmac result
-// 2. the second array is the hmac internal key, you can always ignore it
-pub fn hmac<H: Digest>(key: &[u8], message: &[u8]) -> [u8; H::DIGEST_LENGTH]
+use super::Mac;
+use crate::{
+    hash::{md5::MD5, sha1::SHA1, sha256::SHA256, sha384::SHA384, sha512::SHA512, Digest, Hasher},
+    utils::xor::xor,
+};
+
+// inner and outer hash states with the padded key already absorbed,
+// clone it to authenticate many messages with the same key
+#[derive(Debug, Clone)]
+pub struct Hmac<H: Digest>
 where
-    [(); H::BLOCK_LENGTH]:,
+    [u8; H::BLOCK_LENGTH]: Sized,
 {
-    let key = if key.len() > H::BLOCK_LENGTH {
-        let mut k = [0u8; H::BLOCK_LENGTH];
-        k[..H::BLOCK_LENGTH].copy_from_slice(&H::new().digest(key));
-        k
-    } else {
+    inner: Hasher<H>,
+    outer: H,
+}
+
+// NOTE: `[u8; N]: Sized` instead of `[(); N]:`, the latter overflows
+// when the function is instantiated from another crate
+impl<H: Digest> Hmac<H>
+where
+    [u8; H::BLOCK_LENGTH]: Sized,
+    [u8; H::DIGEST_LENGTH]: Sized,
+{
+    // keys longer than block size are hashed first
+    pub fn new_from_slice(key: &[u8]) -> Self {
         let mut k = [0u8; H::BLOCK_LENGTH];
-        // TODO: digest length larger than block size (wtf?)
-        k[..key.len()].copy_from_slice(key);
-        k
-    };
-
-    let mut i_key_pad = [0x36u8; H::BLOCK_LENGTH];
-    xor_static(&mut i_key_pad, &key);
-
-    let i_msg_hash = {
-        let mut h = H::new();
-        h.update(&i_key_pad);
-        h.digest(message)
-    };
-
-    let mut o_key_pad = [0x5cu8; H::BLOCK_LENGTH];
-    xor_static(&mut o_key_pad, &key);
-
-    let o_msg_hash = {
-        let mut h = H::new();
-        h.update(&o_key_pad);
-        h.digest(&i_msg_hash)
-    };
-
-    o_msg_hash
+        if key.len() > H::BLOCK_LENGTH {
+            let digest = H::new().digest(key);
+            k[..digest.len()].copy_from_slice(&digest);
+        } else {
+            k[..key.len()].copy_from_slice(key);
+        }
+
+        let mut pad = [0x36u8; H::BLOCK_LENGTH];
+        xor(&mut pad, &k);
+        let mut inner = Hasher::<H>::new();
+        inner.update(&pad);
+
+        pad.fill(0x5c);
+        xor(&mut pad, &k);
+        let mut outer = H::new();
+        outer.update(&pad);
+
+        Self { inner, outer }
+    }
+
+    // arbitrary-sized input
+    pub fn update_slice(&mut self, data: &[u8]) {
+        self.inner.update(data);
+    }
+
+    pub fn digest(self) -> [u8; H::DIGEST_LENGTH] {
+        let Self { inner, outer } = self;
+        outer.digest(&inner.finalize())
+    }
+}
+
+// a generic `impl<H: Digest> Mac for Hmac<H>` hits a cycle in generic_const_exprs,
+// so implement it for every digest; the signatures spell out the digest constants
+// because `Self::KEY_LENGTH` cycles through the `Hasher` buffer bound
+macro_rules! impl_mac {
+    ($($h:ty),*) => {$(
+        impl Mac for Hmac<$h> {
+            const KEY_LENGTH: usize = <$h>::DIGEST_LENGTH;
+            const BLOCK_LENGTH: usize = <$h>::BLOCK_LENGTH;
+            const MAC_LENGTH: usize = <$h>::DIGEST_LENGTH;
+
+            fn new(key: &[u8; <$h>::DIGEST_LENGTH]) -> Self {
+                Self::new_from_slice(key)
+            }
+
+            fn update(&mut self, data: &[u8; <$h>::BLOCK_LENGTH]) {
+                self.inner.update(data);
+            }
+
+            fn finalize(mut self, remainder: &[u8]) -> [u8; <$h>::DIGEST_LENGTH] {
+                self.update_slice(remainder);
+                self.digest()
+            }
+        }
+    )*};
+}
+
+impl_mac!(MD5, SHA1, SHA256, SHA384, SHA512);
+
+pub fn hmac<H: Digest>(key: &[u8], message: &[u8]) -> [u8; H::DIGEST_LENGTH]
+where
+    [u8; H::BLOCK_LENGTH]: Sized,
+    [u8; H::DIGEST_LENGTH]: Sized,
+{
+    let mut h = Hmac::<H>::new_from_slice(key);
+    h.update_slice(message);
+    h.digest()
 }
@@ -1,6 +1,8 @@
+pub mod cmac;
 pub mod ghash;
 pub mod hmac;
 pub mod poly1305;
+pub mod polyval;
 
 pub trait Mac {
     const KEY_LENGTH: usize;
@@ -0,0 +1,10 @@
+// RFC8452 https://tools.ietf.org/html/rfc8452
+//
+// POLYVAL shares its backends with GHash, see `ghash::{soft, clmul}`.
+
+pub use super::ghash::Polyval;
+use super::Mac;
+
+pub fn polyval(key: &[u8; 16], message: &[u8]) -> [u8; 16] {
+    Polyval::new(key).finalize(message)
+}
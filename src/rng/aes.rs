@@ -1,22 +1,30 @@
-use crate::{rng::Rng, symmetry::aes::Aes128};
+use crate::{
+    rng::Rng,
+    symmetry::{aes::Aes128, BlockCipher},
+};
 
 #[derive(Debug)]
-pub struct Aes128Rng {
+pub struct BlockCipherRng<C> {
     state: [u8; 16],
-    cipher: Aes128,
+    cipher: C,
 }
 
-impl Aes128Rng {
+impl<C: BlockCipher<16>> BlockCipherRng<C> {
+    const VALID_KEY_LENGTH: () = assert!(C::KEY_LENGTH <= 32);
+
     #[cfg(feature = "system-random")]
-    pub fn new_from_system() -> Self {
+    pub fn new_from_system() -> Self
+    where
+        Self: Rng,
+    {
         let mut seed = [0u8; 32];
         getrandom::getrandom(&mut seed).unwrap();
         Self::new_from_seed(&seed)
     }
 
     fn next_key(&mut self) -> [u8; 16] {
-        let counter: &mut [u8; 8] = &mut self.state[..8].try_into().unwrap();
-        *counter = u64::from_ne_bytes(*counter).wrapping_add(1).to_ne_bytes();
+        let counter = u64::from_ne_bytes(self.state[..8].try_into().unwrap()).wrapping_add(1);
+        self.state[..8].copy_from_slice(&counter.to_ne_bytes());
 
         let mut r = self.state;
         self.cipher.encrypt(&mut r);
@@ -24,10 +32,17 @@ impl Aes128Rng {
     }
 }
 
-impl Rng for Aes128Rng {
+pub type Aes128Rng = BlockCipherRng<Aes128>;
+
+impl<C: BlockCipher<16>> Rng for BlockCipherRng<C>
+where
+    [u8; C::KEY_LENGTH]: Sized,
+{
     fn new_from_seed(seed: &[u8; 32]) -> Self {
-        let state = seed[..16].try_into().unwrap();
-        let cipher = Aes128::new(seed[16..].try_into().unwrap());
+        let () = Self::VALID_KEY_LENGTH;
+
+        let state = [0u8; 16];
+        let cipher = C::new(seed[..C::KEY_LENGTH].try_into().unwrap());
         Self { state, cipher }
     }
     fn fill_bytes(&mut self, data: &mut [u8]) {
@@ -4,86 +4,147 @@ use core::arch::x86::*;
 use core::arch::x86_64::*;
 use core::mem;
 
-macro_rules! gen_round_key {
-    ($rk:ident, $i:literal, $rcon:literal) => {{
-        let mut key = $rk[$i - 1];
-        let gen = _mm_shuffle_epi32(_mm_aeskeygenassist_si128::<$rcon>(key), 0xff);
-        key = _mm_xor_si128(key, _mm_slli_si128(key, 4));
-        key = _mm_xor_si128(key, _mm_slli_si128(key, 8));
-        $rk[$i] = _mm_xor_si128(key, gen);
-    }};
+use super::expand_key;
+use crate::symmetry::BlockCipher;
+
+// SubWord of the second word, taken from AESKEYGENASSIST
+fn sub_word(x: [u8; 4]) -> [u8; 4] {
+    unsafe {
+        let v = _mm_set1_epi32(i32::from_le_bytes(x));
+        _mm_cvtsi128_si32(_mm_aeskeygenassist_si128::<0>(v)).to_le_bytes()
+    }
 }
 
-#[derive(Debug, Clone, Copy)]
-pub struct Aes128 {
-    round_key: [__m128i; 20],
+// independent blocks interleaved to hide the latency of AESENC/AESDEC
+const PARALLEL_BLOCKS: usize = 4;
+
+unsafe fn load(blocks: &[[u8; 16]; PARALLEL_BLOCKS]) -> [__m128i; PARALLEL_BLOCKS] {
+    blocks.map(|b| _mm_loadu_si128(b.as_ptr().cast()))
 }
 
-impl Aes128 {
-    pub fn new(key: &[u8; 16]) -> Self {
-        unsafe {
-            let mut rk: [__m128i; 20] = mem::zeroed();
-
-            rk[0] = _mm_loadu_si128(key.as_ptr().cast());
-
-            gen_round_key!(rk, 1, 0x01);
-            gen_round_key!(rk, 2, 0x02);
-            gen_round_key!(rk, 3, 0x04);
-            gen_round_key!(rk, 4, 0x08);
-            gen_round_key!(rk, 5, 0x10);
-            gen_round_key!(rk, 6, 0x20);
-            gen_round_key!(rk, 7, 0x40);
-            gen_round_key!(rk, 8, 0x80);
-            gen_round_key!(rk, 9, 0x1b);
-            gen_round_key!(rk, 10, 0x36);
-
-            rk[11] = _mm_aesimc_si128(rk[9]);
-            rk[12] = _mm_aesimc_si128(rk[8]);
-            rk[13] = _mm_aesimc_si128(rk[7]);
-            rk[14] = _mm_aesimc_si128(rk[6]);
-            rk[15] = _mm_aesimc_si128(rk[5]);
-            rk[16] = _mm_aesimc_si128(rk[4]);
-            rk[17] = _mm_aesimc_si128(rk[3]);
-            rk[18] = _mm_aesimc_si128(rk[2]);
-            rk[19] = _mm_aesimc_si128(rk[1]);
-
-            Self { round_key: rk }
-        }
+unsafe fn store(blocks: &mut [[u8; 16]; PARALLEL_BLOCKS], b: [__m128i; PARALLEL_BLOCKS]) {
+    for (o, x) in blocks.iter_mut().zip(b) {
+        _mm_storeu_si128(o.as_mut_ptr().cast(), x);
     }
+}
 
-    pub fn encrypt(&self, data: &mut [u8; 16]) {
-        unsafe {
-            let mut b = _mm_loadu_si128(data.as_ptr().cast());
-            b = _mm_xor_si128(b, self.round_key[0]);
-            b = _mm_aesenc_si128(b, self.round_key[1]);
-            b = _mm_aesenc_si128(b, self.round_key[2]);
-            b = _mm_aesenc_si128(b, self.round_key[3]);
-            b = _mm_aesenc_si128(b, self.round_key[4]);
-            b = _mm_aesenc_si128(b, self.round_key[5]);
-            b = _mm_aesenc_si128(b, self.round_key[6]);
-            b = _mm_aesenc_si128(b, self.round_key[7]);
-            b = _mm_aesenc_si128(b, self.round_key[8]);
-            b = _mm_aesenc_si128(b, self.round_key[9]);
-            b = _mm_aesenclast_si128(b, self.round_key[10]);
-            _mm_storeu_si128(data.as_mut_ptr().cast(), b);
-        }
+unsafe fn xor_all(b: &mut [__m128i; PARALLEL_BLOCKS], key: __m128i) {
+    for x in b {
+        *x = _mm_xor_si128(*x, key);
     }
+}
 
-    pub fn decrypt(&self, data: &mut [u8; 16]) {
-        unsafe {
-            let mut b = _mm_loadu_si128(data.as_ptr().cast());
-            b = _mm_xor_si128(b, self.round_key[10]);
-            b = _mm_aesdec_si128(b, self.round_key[11]);
-            b = _mm_aesdec_si128(b, self.round_key[12]);
-            b = _mm_aesdec_si128(b, self.round_key[13]);
-            b = _mm_aesdec_si128(b, self.round_key[14]);
-            b = _mm_aesdec_si128(b, self.round_key[15]);
-            b = _mm_aesdec_si128(b, self.round_key[16]);
-            b = _mm_aesdec_si128(b, self.round_key[17]);
-            b = _mm_aesdec_si128(b, self.round_key[18]);
-            b = _mm_aesdec_si128(b, self.round_key[19]);
-            b = _mm_aesdeclast_si128(b, self.round_key[0]);
-            _mm_storeu_si128(data.as_mut_ptr().cast(), b);
+// round_key[0..=rounds] for encryption,
+// round_key[rounds + 1..] are the inverse mixed round_key[rounds - 1..=1] for decryption
+macro_rules! impl_aes {
+    ($name:ident, $key_size:literal, $rounds:literal) => {
+        #[derive(Debug, Clone, Copy)]
+        pub struct $name {
+            round_key: [__m128i; 2 * $rounds],
         }
-    }
+
+        impl $name {
+            pub fn new(key: &[u8; $key_size]) -> Self {
+                let mut w = [[0u8; 4]; 4 * ($rounds + 1)];
+                expand_key(key, &mut w, sub_word);
+
+                unsafe {
+                    let mut rk: [__m128i; 2 * $rounds] = mem::zeroed();
+
+                    for (k, words) in rk.iter_mut().zip(w.as_chunks::<4>().0) {
+                        *k = _mm_loadu_si128(words.as_ptr().cast());
+                    }
+                    for i in 1..$rounds {
+                        rk[2 * $rounds - i] = _mm_aesimc_si128(rk[i]);
+                    }
+
+                    Self { round_key: rk }
+                }
+            }
+
+            pub fn encrypt(&self, data: &mut [u8; 16]) {
+                let rk = &self.round_key;
+                unsafe {
+                    let mut b = _mm_loadu_si128(data.as_ptr().cast());
+                    b = _mm_xor_si128(b, rk[0]);
+                    for k in &rk[1..$rounds] {
+                        b = _mm_aesenc_si128(b, *k);
+                    }
+                    b = _mm_aesenclast_si128(b, rk[$rounds]);
+                    _mm_storeu_si128(data.as_mut_ptr().cast(), b);
+                }
+            }
+
+            pub fn decrypt(&self, data: &mut [u8; 16]) {
+                let rk = &self.round_key;
+                unsafe {
+                    let mut b = _mm_loadu_si128(data.as_ptr().cast());
+                    b = _mm_xor_si128(b, rk[$rounds]);
+                    for k in &rk[$rounds + 1..] {
+                        b = _mm_aesdec_si128(b, *k);
+                    }
+                    b = _mm_aesdeclast_si128(b, rk[0]);
+                    _mm_storeu_si128(data.as_mut_ptr().cast(), b);
+                }
+            }
+        }
+
+        impl BlockCipher<16> for $name {
+            const KEY_LENGTH: usize = $key_size;
+
+            fn new(key: &[u8; Self::KEY_LENGTH]) -> Self {
+                Self::new(key)
+            }
+
+            fn encrypt(&self, data: &mut [u8; 16]) {
+                self.encrypt(data);
+            }
+
+            fn decrypt(&self, data: &mut [u8; 16]) {
+                self.decrypt(data);
+            }
+
+            fn encrypt_blocks(&self, data: &mut [[u8; 16]]) {
+                let rk = &self.round_key;
+                let (chunks, remainder) = data.as_chunks_mut::<PARALLEL_BLOCKS>();
+                for chunk in chunks {
+                    unsafe {
+                        let mut b = load(chunk);
+                        xor_all(&mut b, rk[0]);
+                        for k in &rk[1..$rounds] {
+                            b = b.map(|x| _mm_aesenc_si128(x, *k));
+                        }
+                        b = b.map(|x| _mm_aesenclast_si128(x, rk[$rounds]));
+                        store(chunk, b);
+                    }
+                }
+                for block in remainder {
+                    self.encrypt(block);
+                }
+            }
+
+            fn decrypt_blocks(&self, data: &mut [[u8; 16]]) {
+                let rk = &self.round_key;
+                let (chunks, remainder) = data.as_chunks_mut::<PARALLEL_BLOCKS>();
+                for chunk in chunks {
+                    unsafe {
+                        let mut b = load(chunk);
+                        xor_all(&mut b, rk[$rounds]);
+                        for k in &rk[$rounds + 1..] {
+                            b = b.map(|x| _mm_aesdec_si128(x, *k));
+                        }
+                        b = b.map(|x| _mm_aesdeclast_si128(x, rk[0]));
+                        store(chunk, b);
+                    }
+                }
+                for block in remainder {
+                    self.decrypt(block);
+                }
+            }
+        }
+    };
 }
+
+impl_aes!(Aes128, 16, 10);
+impl_aes!(Aes192, 24, 12);
+impl_aes!(Aes256, 32, 14);
@@ -1,3 +1,5 @@
+// FIPS-197 https://nvlpubs.nist.gov/nistpubs/FIPS/nist.fips.197.pdf
+
 #[cfg(not(feature = "aesni"))]
 mod soft;
 #[cfg(not(feature = "aesni"))]
@@ -10,3 +12,29 @@ pub use aesni::*;
 
 // #[cfg(all(not(target_feature = "aes"), feature = "aesni"))]
 // compile_error!("Use `aesni` feature without target aes support");
+
+const RCONSTANT: [u8; 10] = [0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x80, 0x1b, 0x36];
+
+// KeyExpansion of FIPS-197 section 5.2 on words of 4 bytes,
+// the backend provides SubWord
+fn expand_key(key: &[u8], w: &mut [[u8; 4]], sub_word: impl Fn([u8; 4]) -> [u8; 4]) {
+    let nk = key.len() / 4;
+    for (o, i) in w.iter_mut().zip(key.as_chunks().0) {
+        *o = *i;
+    }
+
+    for i in nk..w.len() {
+        let mut temp = w[i - 1];
+        if i % nk == 0 {
+            temp.rotate_left(1);
+            temp = sub_word(temp);
+            temp[0] ^= RCONSTANT[i / nk - 1];
+        } else if nk > 6 && i % nk == 4 {
+            temp = sub_word(temp);
+        }
+        for (t, x) in temp.iter_mut().zip(w[i - nk]) {
+            *t ^= x;
+        }
+        w[i] = temp;
+    }
+}
@@ -1,6 +1,10 @@
-use crate::utils::{
-    endian::{BigEndian, EndianConvertion},
-    xor::xor,
+use super::expand_key;
+use crate::{
+    symmetry::BlockCipher,
+    utils::{
+        endian::{BigEndian, EndianConvertion},
+        xor::xor,
+    },
 };
 
 const SBOX: [u8; 256] = [
@@ -41,8 +45,6 @@ const RSBOX: [u8; 256] = [
     0x17, 0x2b, 0x04, 0x7e, 0xba, 0x77, 0xd6, 0x26, 0xe1, 0x69, 0x14, 0x63, 0x55, 0x21, 0x0c, 0x7d,
 ];
 
-const RCONSTANT: [u8; 10] = [0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x80, 0x1b, 0x36];
-
 #[inline(always)]
 fn byte(i: u32, n: u32) -> u8 {
     (i >> ((3 - n) * 8)) as u8
@@ -161,81 +163,98 @@ fn inv_mix_columns(data: &mut [u32; 4]) {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-pub struct Aes128 {
-    round_key: [[u32; 4]; 11],
-}
+macro_rules! impl_aes {
+    ($name:ident, $key_size:literal, $rounds:literal) => {
+        #[derive(Debug, Clone, Copy)]
+        pub struct $name {
+            round_key: [[u32; 4]; $rounds + 1],
+        }
 
-impl Aes128 {
-    pub fn new(key: &[u8; 16]) -> Self {
-        let mut round_key = [[0u32; 4]; 11];
+        impl $name {
+            pub fn new(key: &[u8; $key_size]) -> Self {
+                let mut w = [[0u8; 4]; 4 * ($rounds + 1)];
+                expand_key(key, &mut w, |x| x.map(|b| SBOX[b as usize]));
 
-        BigEndian::from_bytes(&mut round_key[0], key);
+                let mut round_key = [[0u32; 4]; $rounds + 1];
+                for (k, words) in round_key.iter_mut().zip(w.as_chunks::<4>().0) {
+                    *k = words.map(u32::from_be_bytes);
+                }
+                Self { round_key }
+            }
 
-        for i in 0..round_key.len() - 1 {
-            let (a, b) = (round_key[i], &mut round_key[i + 1]);
+            pub fn encrypt(&self, data: &mut [u8; 16]) {
+                encrypt(&self.round_key, data);
+            }
 
-            // rot word, sub word
-            let mut temp = sub_byte(a[3].rotate_left(8), &SBOX);
+            pub fn decrypt(&self, data: &mut [u8; 16]) {
+                decrypt(&self.round_key, data);
+            }
+        }
 
-            // rconstant
-            temp ^= (RCONSTANT[i] as u32) << 24;
+        impl BlockCipher<16> for $name {
+            const KEY_LENGTH: usize = $key_size;
 
-            *b = a;
+            fn new(key: &[u8; Self::KEY_LENGTH]) -> Self {
+                Self::new(key)
+            }
 
-            for chunk in b {
-                *chunk ^= temp;
-                temp = *chunk;
+            fn encrypt(&self, data: &mut [u8; 16]) {
+                self.encrypt(data);
+            }
+
+            fn decrypt(&self, data: &mut [u8; 16]) {
+                self.decrypt(data);
             }
         }
-        Aes128 { round_key }
-    }
+    };
+}
 
-    pub fn encrypt(&self, data: &mut [u8; 16]) {
-        let keys = self.round_key;
-        let (first_key, keys) = keys.split_first().unwrap();
-        let (last_key, keys) = keys.split_last().unwrap();
+impl_aes!(Aes128, 16, 10);
+impl_aes!(Aes192, 24, 12);
+impl_aes!(Aes256, 32, 14);
 
-        let blocks = &mut [0u32; 4];
-        BigEndian::from_bytes(blocks, data);
+fn encrypt(keys: &[[u32; 4]], data: &mut [u8; 16]) {
+    let (first_key, keys) = keys.split_first().unwrap();
+    let (last_key, keys) = keys.split_last().unwrap();
 
-        xor(blocks, first_key);
+    let blocks = &mut [0u32; 4];
+    BigEndian::from_bytes(blocks, data);
 
-        for key in keys {
-            sub_bytes(blocks);
-            shift_rows(blocks);
-            mix_columns(blocks);
-            xor(blocks, key);
-        }
+    xor(blocks, first_key);
 
+    for key in keys {
         sub_bytes(blocks);
         shift_rows(blocks);
-        xor(blocks, last_key);
-
-        BigEndian::to_bytes(data, blocks);
+        mix_columns(blocks);
+        xor(blocks, key);
     }
 
-    pub fn decrypt(&self, data: &mut [u8; 16]) {
-        let keys = self.round_key;
-        let (first_key, keys) = keys.split_first().unwrap();
-        let (last_key, keys) = keys.split_last().unwrap();
+    sub_bytes(blocks);
+    shift_rows(blocks);
+    xor(blocks, last_key);
 
-        let blocks = &mut [0u32; 4];
-        BigEndian::from_bytes(blocks, data);
+    BigEndian::to_bytes(data, blocks);
+}
 
-        xor(blocks, last_key);
-        inv_shift_rows(blocks);
-        inv_sub_bytes(blocks);
+fn decrypt(keys: &[[u32; 4]], data: &mut [u8; 16]) {
+    let (first_key, keys) = keys.split_first().unwrap();
+    let (last_key, keys) = keys.split_last().unwrap();
 
-        for key in keys.iter().rev() {
-            xor(blocks, key);
-            inv_mix_columns(blocks);
-            inv_shift_rows(blocks);
-            inv_sub_bytes(blocks);
-        }
+    let blocks = &mut [0u32; 4];
+    BigEndian::from_bytes(blocks, data);
 
-        xor(blocks, first_key);
+    xor(blocks, last_key);
+    inv_shift_rows(blocks);
+    inv_sub_bytes(blocks);
 
-        BigEndian::to_bytes(data, blocks);
+    for key in keys.iter().rev() {
+        xor(blocks, key);
+        inv_mix_columns(blocks);
+        inv_shift_rows(blocks);
+        inv_sub_bytes(blocks);
     }
+
+    xor(blocks, first_key);
+
+    BigEndian::to_bytes(data, blocks);
 }
@@ -1,6 +1,9 @@
-use crate::utils::{
-    endian::{assert_len_mut, EndianConvertion, LittleEndian},
-    xor::xor,
+use crate::{
+    error::CipherError,
+    utils::{
+        endian::{assert_len_mut, EndianConvertion, LittleEndian},
+        xor::xor,
+    },
 };
 
 // "expand 32-byte k"
@@ -23,7 +26,8 @@ impl<const R: usize> ChaChaInner<R> {
         Self(state)
     }
 
-    fn round(&self) -> [u32; 16] {
+    // the rounds without the final addition of the input state
+    fn permute(&self) -> [u32; 16] {
         fn quarter_round_idx(e: &mut [u32; 16], ai: usize, bi: usize, ci: usize, di: usize) {
             let [mut a, mut b, mut c, mut d] = [e[ai], e[bi], e[ci], e[di]];
 
@@ -61,6 +65,11 @@ impl<const R: usize> ChaChaInner<R> {
             quarter_round_idx(&mut state, 0x3, 0x4, 0x9, 0xE);
         }
 
+        state
+    }
+
+    fn round(&self) -> [u32; 16] {
+        let mut state = self.permute();
         state
             .iter_mut()
             .zip(self.0)
@@ -77,42 +86,209 @@ impl<const R: usize> ChaChaInner<R> {
     }
 }
 
+// RFC8439 2.3, a 32-bit block counter and a 96-bit nonce
 #[derive(Debug)]
-pub struct ChaCha20 {
-    inner: ChaChaInner<20>,
+pub struct ChaCha<const R: usize> {
+    inner: ChaChaInner<R>,
+    // bytes of keystream from block 0, not from the initial counter
+    position: u64,
+    // keystream of the current block, valid while `position` isn't block aligned
     buffer: [u8; 64],
-    buffer_offset: usize,
 }
 
-impl ChaCha20 {
+pub type ChaCha8 = ChaCha<8>;
+pub type ChaCha12 = ChaCha<12>;
+pub type ChaCha20 = ChaCha<20>;
+
+impl<const R: usize> ChaCha<R> {
+    // the keystream ends after the block with counter 2^32-1, it never wraps around
+    const MAX_POSITION: u64 = 64 << 32;
+
     pub fn new(key: &[u8; 32], nonce: &[u8; 12]) -> Self {
+        Self::new_with_counter(key, nonce, 0)
+    }
+
+    // RFC8439 2.4, the keystream starts from the `counter`-th block
+    pub fn new_with_counter(key: &[u8; 32], nonce: &[u8; 12], counter: u32) -> Self {
         let mut inner = ChaChaInner::new(key, nonce);
-        let buffer = inner.next_key();
+        inner.0[12] = counter;
 
-        ChaCha20 {
+        Self {
             inner,
-            buffer,
-            buffer_offset: 0,
+            position: counter as u64 * 64,
+            buffer: [0u8; 64],
         }
     }
 
-    pub fn apply(&mut self, data: &mut [u8]) {
-        xor(data, &self.buffer[self.buffer_offset..]);
+    fn set_counter(&mut self, counter: u64) {
+        self.inner.0[12] = counter as u32;
+    }
 
-        // buffer still have unused key
-        if data.len() < self.buffer[self.buffer_offset..].len() {
-            self.buffer_offset += data.len();
-            return;
+    fn next_key(&mut self) -> [u8; 64] {
+        self.inner.next_key()
+    }
+}
+
+// the original construction by DJB, a 64-bit block counter and a 64-bit nonce,
+// `rand_chacha` takes the nonce as the stream id
+#[derive(Debug)]
+pub struct ChaChaLegacy<const R: usize> {
+    inner: ChaChaInner<R>,
+    position: u128,
+    buffer: [u8; 64],
+}
+
+pub type ChaCha8Legacy = ChaChaLegacy<8>;
+pub type ChaCha12Legacy = ChaChaLegacy<12>;
+pub type ChaCha20Legacy = ChaChaLegacy<20>;
+
+impl<const R: usize> ChaChaLegacy<R> {
+    const MAX_POSITION: u128 = 64 << 64;
+
+    pub fn new(key: &[u8; 32], nonce: &[u8; 8]) -> Self {
+        Self::new_with_counter(key, nonce, 0)
+    }
+
+    pub fn new_with_counter(key: &[u8; 32], nonce: &[u8; 8], counter: u64) -> Self {
+        // the counter takes the first nonce word of RFC8439
+        let mut ietf_nonce = [0u8; 12];
+        ietf_nonce[4..].copy_from_slice(nonce);
+
+        let mut this = Self {
+            inner: ChaChaInner::new(key, &ietf_nonce),
+            position: counter as u128 * 64,
+            buffer: [0u8; 64],
+        };
+        this.set_counter(counter as u128);
+        this
+    }
+
+    fn set_counter(&mut self, counter: u128) {
+        self.inner.0[12] = counter as u32;
+        self.inner.0[13] = (counter >> 32) as u32;
+    }
+
+    fn next_key(&mut self) -> [u8; 64] {
+        let key = self.inner.next_key();
+        if self.inner.0[12] == 0 {
+            self.inner.0[13] = self.inner.0[13].wrapping_add(1);
         }
+        key
+    }
+}
 
-        // no key left in buffer
-        let (chunks, remain) = data[64 - self.buffer_offset..].as_chunks_mut::<64>();
-        for chunk in chunks {
-            xor(chunk, &self.inner.next_key());
+// the keystream API over a counter of `$position` bytes
+macro_rules! impl_stream {
+    ($($name:ident: $position:ty),*) => {$(
+        impl<const R: usize> $name<R> {
+            pub fn current_position(&self) -> $position {
+                self.position
+            }
+
+            // jump to `position` bytes from the start of block 0
+            pub fn seek(&mut self, position: $position) -> Result<(), CipherError> {
+                if position > Self::MAX_POSITION {
+                    return Err(CipherError::CounterOverflow);
+                }
+
+                self.position = position;
+                self.set_counter(position / 64);
+                if position % 64 != 0 {
+                    self.buffer = self.next_key();
+                }
+                Ok(())
+            }
+
+            // panic if the block counter would wrap around, see `try_apply`
+            pub fn apply(&mut self, data: &mut [u8]) {
+                self.try_apply(data).expect("ChaCha block counter overflow");
+            }
+
+            // `data` is left untouched if the block counter would wrap around
+            pub fn try_apply(&mut self, mut data: &mut [u8]) -> Result<(), CipherError> {
+                if data.len() as $position > Self::MAX_POSITION - self.position {
+                    return Err(CipherError::CounterOverflow);
+                }
+
+                // finish the partially used block
+                let offset = (self.position % 64) as usize;
+                if offset != 0 {
+                    let n = data.len().min(64 - offset);
+                    let (head, tail) = data.split_at_mut(n);
+                    xor(head, &self.buffer[offset..]);
+                    self.position += n as $position;
+                    data = tail;
+                }
+
+                let (chunks, remainder) = data.as_chunks_mut::<64>();
+                for chunk in chunks.iter_mut() {
+                    xor(chunk, &self.next_key());
+                }
+                self.position += (chunks.len() * 64) as $position;
+
+                if !remainder.is_empty() {
+                    self.buffer = self.next_key();
+                    xor(remainder, &self.buffer);
+                    self.position += remainder.len() as $position;
+                }
+                Ok(())
+            }
         }
-        self.buffer = self.inner.next_key();
+    )*};
+}
+
+impl_stream!(ChaCha: u64, ChaChaLegacy: u128);
+
+// draft-irtf-cfrg-xchacha-03 2.2
+// the first 4 bytes of the nonce take the place of the block counter
+pub fn hchacha20(key: &[u8; 32], nonce: &[u8; 16]) -> [u8; 32] {
+    let mut inner = ChaChaInner::<20>::new(key, nonce[4..].try_into().unwrap());
+    inner.0[12] = u32::from_le_bytes(nonce[..4].try_into().unwrap());
+
+    let state = inner.permute();
+    let mut output = [0u8; 32];
+    let words = state[..4].iter().chain(&state[12..]);
+    for (o, w) in output.as_chunks_mut::<4>().0.iter_mut().zip(words) {
+        *o = w.to_le_bytes();
+    }
+    output
+}
+
+// draft-irtf-cfrg-xchacha-03 2.3
+#[derive(Debug)]
+pub struct XChaCha20(ChaCha20);
+
+impl XChaCha20 {
+    pub fn new(key: &[u8; 32], nonce: &[u8; 24]) -> Self {
+        Self::new_with_counter(key, nonce, 0)
+    }
+
+    pub fn new_with_counter(key: &[u8; 32], nonce: &[u8; 24], counter: u32) -> Self {
+        let (subkey, nonce) = xchacha20_subkey(key, nonce);
+        Self(ChaCha20::new_with_counter(&subkey, &nonce, counter))
+    }
+
+    pub fn current_position(&self) -> u64 {
+        self.0.current_position()
+    }
 
-        xor(remain, &self.buffer);
-        self.buffer_offset = remain.len();
+    pub fn seek(&mut self, position: u64) -> Result<(), CipherError> {
+        self.0.seek(position)
     }
+
+    pub fn apply(&mut self, data: &mut [u8]) {
+        self.0.apply(data);
+    }
+
+    pub fn try_apply(&mut self, data: &mut [u8]) -> Result<(), CipherError> {
+        self.0.try_apply(data)
+    }
+}
+
+// derive the ChaCha20 key and nonce from an XChaCha20 key and nonce
+fn xchacha20_subkey(key: &[u8; 32], nonce: &[u8; 24]) -> ([u8; 32], [u8; 12]) {
+    let subkey = hchacha20(key, nonce[..16].try_into().unwrap());
+    let mut chacha_nonce = [0u8; 12];
+    chacha_nonce[4..].copy_from_slice(&nonce[16..]);
+    (subkey, chacha_nonce)
 }
@@ -1,2 +1,26 @@
 pub mod aes;
 pub mod chacha;
+pub mod mode;
+
+// the block length is a parameter instead of an associated const,
+// so modes of operation can require a specific one, e.g. `C: BlockCipher<16>`
+pub trait BlockCipher<const BLOCK_LENGTH: usize>: Sized {
+    const KEY_LENGTH: usize;
+
+    fn new(key: &[u8; Self::KEY_LENGTH]) -> Self;
+    fn encrypt(&self, data: &mut [u8; BLOCK_LENGTH]);
+    fn decrypt(&self, data: &mut [u8; BLOCK_LENGTH]);
+
+    // backends may process several independent blocks in parallel
+    fn encrypt_blocks(&self, data: &mut [[u8; BLOCK_LENGTH]]) {
+        for block in data {
+            self.encrypt(block);
+        }
+    }
+
+    fn decrypt_blocks(&self, data: &mut [[u8; BLOCK_LENGTH]]) {
+        for block in data {
+            self.decrypt(block);
+        }
+    }
+}
@@ -0,0 +1,105 @@
+// NIST SP800-38A 6.2, padding from RFC5652 6.3 (PKCS#7)
+
+use super::PARALLEL_BLOCKS;
+use crate::{error::CipherError, symmetry::BlockCipher, utils::xor::xor_static};
+
+// `data` must be a multiple of the block length
+pub fn encrypt<C: BlockCipher<16>>(
+    cipher: &C,
+    iv: &[u8; 16],
+    data: &mut [u8],
+) -> Result<(), CipherError> {
+    let (blocks, remainder) = data.as_chunks_mut();
+    if !remainder.is_empty() {
+        return Err(CipherError::InvalidLength);
+    }
+
+    let mut previous = iv;
+    for block in blocks {
+        xor_static(block, previous);
+        cipher.encrypt(block);
+        previous = block;
+    }
+    Ok(())
+}
+
+// `data` must be a multiple of the block length
+pub fn decrypt<C: BlockCipher<16>>(
+    cipher: &C,
+    iv: &[u8; 16],
+    data: &mut [u8],
+) -> Result<(), CipherError> {
+    let (blocks, remainder) = data.as_chunks_mut();
+    if !remainder.is_empty() {
+        return Err(CipherError::InvalidLength);
+    }
+
+    // unlike encryption, blocks can be decrypted in parallel
+    let mut previous = *iv;
+    for chunk in blocks.chunks_mut(PARALLEL_BLOCKS) {
+        let mut ciphertext = [[0u8; 16]; PARALLEL_BLOCKS];
+        let ciphertext = &mut ciphertext[..chunk.len()];
+        ciphertext.copy_from_slice(chunk);
+
+        cipher.decrypt_blocks(chunk);
+
+        for (block, c) in chunk.iter_mut().zip(ciphertext.iter()) {
+            xor_static(block, &previous);
+            previous = *c;
+        }
+    }
+    Ok(())
+}
+
+// pad `buffer[..length]` and encrypt it in place, return the ciphertext length
+// `buffer` needs room for up to one extra block of padding
+pub fn encrypt_padded<C: BlockCipher<16>>(
+    cipher: &C,
+    iv: &[u8; 16],
+    buffer: &mut [u8],
+    length: usize,
+) -> Result<usize, CipherError> {
+    let padded_length = (length / 16 + 1) * 16;
+    if buffer.len() < padded_length {
+        return Err(CipherError::InvalidLength);
+    }
+
+    buffer[length..padded_length].fill((padded_length - length) as u8);
+    encrypt(cipher, iv, &mut buffer[..padded_length])?;
+    Ok(padded_length)
+}
+
+// decrypt in place and check the padding, return the plaintext length
+pub fn decrypt_padded<C: BlockCipher<16>>(
+    cipher: &C,
+    iv: &[u8; 16],
+    data: &mut [u8],
+) -> Result<usize, CipherError> {
+    if data.is_empty() {
+        return Err(CipherError::InvalidLength);
+    }
+    decrypt(cipher, iv, data)?;
+
+    let last_block = data.as_chunks().0.last().unwrap();
+    Ok(data.len() - padding_length(last_block)?)
+}
+
+// padding is checked without branching on its content, a padding oracle
+// would otherwise reveal the plaintext
+fn padding_length(block: &[u8; 16]) -> Result<usize, CipherError> {
+    let n = block[15];
+
+    // n - 1 doesn't fit in 4 bits when n is 0 or larger than 16
+    let mut bad = n.wrapping_sub(1) >> 4;
+    for (i, &b) in block.iter().rev().enumerate() {
+        // 0xff if the byte is part of the padding
+        let mask = ((i as u8).wrapping_sub(n) >> 7).wrapping_neg();
+        bad |= mask & (b ^ n);
+    }
+
+    if bad == 0 {
+        Ok(n as usize)
+    } else {
+        Err(CipherError::InvalidPadding)
+    }
+}
@@ -0,0 +1,130 @@
+// NIST SP800-38A 6.5
+
+use super::PARALLEL_BLOCKS;
+use crate::{
+    symmetry::BlockCipher,
+    utils::xor::{xor, xor_static},
+};
+
+// a big-endian counter takes the last bytes of the counter block (SP800-38A, GCM),
+// a little-endian one takes the first bytes (GCM-SIV)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CounterEndian {
+    Big,
+    Little,
+}
+
+// the counter wraps around inside its own bytes, the rest of the block is left untouched
+#[derive(Debug, Clone)]
+pub struct Ctr<C> {
+    cipher: C,
+    iv: [u8; 16],
+    counter_size: usize,
+    endian: CounterEndian,
+    // bytes of keystream used so far
+    position: u64,
+    // keystream of the current block, valid while `position` isn't block aligned
+    buffer: [u8; 16],
+}
+
+impl<C: BlockCipher<16>> Ctr<C> {
+    // the whole block is a 128-bit big-endian counter
+    pub fn new(cipher: C, iv: &[u8; 16]) -> Self {
+        Self::new_with_counter(cipher, iv, 16, CounterEndian::Big)
+    }
+
+    // `counter_size` is in bytes, from 1 to 16
+    pub fn new_with_counter(
+        cipher: C,
+        iv: &[u8; 16],
+        counter_size: usize,
+        endian: CounterEndian,
+    ) -> Self {
+        assert!((1..=16).contains(&counter_size));
+
+        Self {
+            cipher,
+            iv: *iv,
+            counter_size,
+            endian,
+            position: 0,
+            buffer: [0u8; 16],
+        }
+    }
+
+    pub fn current_position(&self) -> u64 {
+        self.position
+    }
+
+    // jump to `position` bytes from the start of the keystream
+    pub fn seek(&mut self, position: u64) {
+        self.position = position;
+        if !position.is_multiple_of(16) {
+            self.buffer = self.keystream(position / 16);
+        }
+    }
+
+    pub fn apply(&mut self, mut data: &mut [u8]) {
+        // finish the partially used block
+        let offset = (self.position % 16) as usize;
+        if offset != 0 {
+            let n = data.len().min(16 - offset);
+            let (head, tail) = data.split_at_mut(n);
+            xor(head, &self.buffer[offset..]);
+            self.position += n as u64;
+            data = tail;
+        }
+
+        let (blocks, remainder) = data.as_chunks_mut::<16>();
+        for chunk in blocks.chunks_mut(PARALLEL_BLOCKS) {
+            let index = self.position / 16;
+
+            let mut keys = [[0u8; 16]; PARALLEL_BLOCKS];
+            let keys = &mut keys[..chunk.len()];
+            for (i, key) in keys.iter_mut().enumerate() {
+                *key = self.counter_block(index + i as u64);
+            }
+            self.cipher.encrypt_blocks(keys);
+
+            for (block, key) in chunk.iter_mut().zip(keys.iter()) {
+                xor_static(block, key);
+            }
+            self.position += (chunk.len() * 16) as u64;
+        }
+
+        if !remainder.is_empty() {
+            self.buffer = self.keystream(self.position / 16);
+            xor(remainder, &self.buffer);
+            self.position += remainder.len() as u64;
+        }
+    }
+
+    fn keystream(&self, index: u64) -> [u8; 16] {
+        let mut block = self.counter_block(index);
+        self.cipher.encrypt(&mut block);
+        block
+    }
+
+    // counter block of the `index`-th keystream block
+    fn counter_block(&self, index: u64) -> [u8; 16] {
+        let mut block = self.iv;
+        let size = self.counter_size;
+        let mut counter = [0u8; 16];
+
+        match self.endian {
+            CounterEndian::Big => {
+                let field = &mut block[16 - size..];
+                counter[16 - size..].copy_from_slice(field);
+                let c = u128::from_be_bytes(counter).wrapping_add(index as u128);
+                field.copy_from_slice(&c.to_be_bytes()[16 - size..]);
+            }
+            CounterEndian::Little => {
+                let field = &mut block[..size];
+                counter[..size].copy_from_slice(field);
+                let c = u128::from_le_bytes(counter).wrapping_add(index as u128);
+                field.copy_from_slice(&c.to_le_bytes()[..size]);
+            }
+        }
+        block
+    }
+}
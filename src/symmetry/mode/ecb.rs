@@ -0,0 +1,24 @@
+// NIST SP800-38A 6.1
+//
+// WARNING: ECB leaks equal plaintext blocks, only use it as a building block.
+
+use crate::{error::CipherError, symmetry::BlockCipher};
+
+// `data` must be a multiple of the block length
+pub fn encrypt<C: BlockCipher<16>>(cipher: &C, data: &mut [u8]) -> Result<(), CipherError> {
+    let (blocks, remainder) = data.as_chunks_mut();
+    if !remainder.is_empty() {
+        return Err(CipherError::InvalidLength);
+    }
+    cipher.encrypt_blocks(blocks);
+    Ok(())
+}
+
+pub fn decrypt<C: BlockCipher<16>>(cipher: &C, data: &mut [u8]) -> Result<(), CipherError> {
+    let (blocks, remainder) = data.as_chunks_mut();
+    if !remainder.is_empty() {
+        return Err(CipherError::InvalidLength);
+    }
+    cipher.decrypt_blocks(blocks);
+    Ok(())
+}
@@ -0,0 +1,162 @@
+// RFC3394 https://tools.ietf.org/html/rfc3394
+// RFC5649 https://tools.ietf.org/html/rfc5649
+
+use crate::{error::CipherError, symmetry::BlockCipher, utils::ct::ct_eq};
+
+const IV: [u8; 8] = [0xa6; 8];
+const PADDED_IV_PREFIX: [u8; 4] = [0xa6, 0x59, 0x59, 0xa6];
+
+// ciphertext length of `wrap_padded` for a key of `length` bytes
+pub const fn padded_wrapped_length(length: usize) -> usize {
+    length.div_ceil(8) * 8 + 8
+}
+
+// RFC3394 2.2.1, return the first 8 bytes of ciphertext, the rest replaces `r`
+fn wrap_in_place<C: BlockCipher<16>>(cipher: &C, mut a: [u8; 8], r: &mut [[u8; 8]]) -> [u8; 8] {
+    let n = r.len();
+    for j in 0..6 {
+        for (i, ri) in r.iter_mut().enumerate() {
+            let mut b = [0u8; 16];
+            b[..8].copy_from_slice(&a);
+            b[8..].copy_from_slice(ri);
+            cipher.encrypt(&mut b);
+
+            let t = (n * j + i + 1) as u64;
+            a = (u64::from_be_bytes(b[..8].try_into().unwrap()) ^ t).to_be_bytes();
+            ri.copy_from_slice(&b[8..]);
+        }
+    }
+    a
+}
+
+// RFC3394 2.2.2, return the recovered IV, the plaintext replaces `r`
+fn unwrap_in_place<C: BlockCipher<16>>(cipher: &C, mut a: [u8; 8], r: &mut [[u8; 8]]) -> [u8; 8] {
+    let n = r.len();
+    for j in (0..6).rev() {
+        for (i, ri) in r.iter_mut().enumerate().rev() {
+            let t = (n * j + i + 1) as u64;
+
+            let mut b = [0u8; 16];
+            b[..8].copy_from_slice(&(u64::from_be_bytes(a) ^ t).to_be_bytes());
+            b[8..].copy_from_slice(ri);
+            cipher.decrypt(&mut b);
+
+            a.copy_from_slice(&b[..8]);
+            ri.copy_from_slice(&b[8..]);
+        }
+    }
+    a
+}
+
+// `key` is at least 16 bytes and a multiple of 8, `output` is 8 bytes longer
+pub fn wrap<C: BlockCipher<16>>(
+    cipher: &C,
+    key: &[u8],
+    output: &mut [u8],
+) -> Result<(), CipherError> {
+    if key.len() < 16 || !key.len().is_multiple_of(8) || output.len() != key.len() + 8 {
+        return Err(CipherError::InvalidLength);
+    }
+
+    let (a, r) = output.split_at_mut(8);
+    r.copy_from_slice(key);
+    let r = r.as_chunks_mut().0;
+    a.copy_from_slice(&wrap_in_place(cipher, IV, r));
+    Ok(())
+}
+
+// `output` is 8 bytes shorter than `wrapped`, and is zeroed if the integrity check fails
+pub fn unwrap<C: BlockCipher<16>>(
+    cipher: &C,
+    wrapped: &[u8],
+    output: &mut [u8],
+) -> Result<(), CipherError> {
+    if wrapped.len() < 24 || !wrapped.len().is_multiple_of(8) || output.len() + 8 != wrapped.len() {
+        return Err(CipherError::InvalidLength);
+    }
+
+    output.copy_from_slice(&wrapped[8..]);
+    let a = unwrap_in_place(
+        cipher,
+        wrapped[..8].try_into().unwrap(),
+        output.as_chunks_mut().0,
+    );
+
+    if !ct_eq(&a, &IV) {
+        output.fill(0);
+        return Err(CipherError::IntegrityCheckFailed);
+    }
+    Ok(())
+}
+
+// `key` is not empty, `output` is `padded_wrapped_length(key.len())` bytes
+pub fn wrap_padded<C: BlockCipher<16>>(
+    cipher: &C,
+    key: &[u8],
+    output: &mut [u8],
+) -> Result<(), CipherError> {
+    if key.is_empty() || key.len() > u32::MAX as usize {
+        return Err(CipherError::InvalidLength);
+    }
+    if output.len() != padded_wrapped_length(key.len()) {
+        return Err(CipherError::InvalidLength);
+    }
+
+    let mut iv = [0u8; 8];
+    iv[..4].copy_from_slice(&PADDED_IV_PREFIX);
+    iv[4..].copy_from_slice(&(key.len() as u32).to_be_bytes());
+
+    let (a, r) = output.split_at_mut(8);
+    r[..key.len()].copy_from_slice(key);
+    r[key.len()..].fill(0);
+
+    if r.len() == 8 {
+        // a single block is encrypted directly
+        a.copy_from_slice(&iv);
+        cipher.encrypt(output.try_into().unwrap());
+    } else {
+        a.copy_from_slice(&wrap_in_place(cipher, iv, r.as_chunks_mut().0));
+    }
+    Ok(())
+}
+
+// `output` is at least 8 bytes shorter than `wrapped` and is zeroed if the integrity check fails,
+// return the key length
+pub fn unwrap_padded<C: BlockCipher<16>>(
+    cipher: &C,
+    wrapped: &[u8],
+    output: &mut [u8],
+) -> Result<usize, CipherError> {
+    if wrapped.len() < 16 || !wrapped.len().is_multiple_of(8) || output.len() + 8 < wrapped.len() {
+        return Err(CipherError::InvalidLength);
+    }
+
+    let plaintext = &mut output[..wrapped.len() - 8];
+    let a: [u8; 8] = if wrapped.len() == 16 {
+        let mut block: [u8; 16] = wrapped.try_into().unwrap();
+        cipher.decrypt(&mut block);
+        plaintext.copy_from_slice(&block[8..]);
+        block[..8].try_into().unwrap()
+    } else {
+        plaintext.copy_from_slice(&wrapped[8..]);
+        unwrap_in_place(
+            cipher,
+            wrapped[..8].try_into().unwrap(),
+            plaintext.as_chunks_mut().0,
+        )
+    };
+
+    // RFC5649 3, the length must fit in the last block and the padding must be zeros
+    let length = u32::from_be_bytes(a[4..].try_into().unwrap()) as usize;
+    let mut bad = !ct_eq(&a[..4], &PADDED_IV_PREFIX);
+    bad |= length > plaintext.len() || plaintext.len() - length >= 8;
+    if !bad {
+        bad |= plaintext[length..].iter().any(|&x| x != 0);
+    }
+
+    if bad {
+        plaintext.fill(0);
+        return Err(CipherError::IntegrityCheckFailed);
+    }
+    Ok(length)
+}
@@ -0,0 +1,14 @@
+// NIST SP800-38A https://nvlpubs.nist.gov/nistpubs/Legacy/SP/nistspecialpublication800-38a.pdf
+//
+// Modes of operation for 128-bit block ciphers, working in place on caller buffers.
+// NOTE: the block length is spelled `16` in signatures, a named const there
+// makes generic_const_exprs cycle when instantiated from another crate
+
+pub mod cbc;
+pub mod ctr;
+pub mod ecb;
+pub mod kw;
+pub mod xts;
+
+// blocks passed to `BlockCipher::{encrypt, decrypt}_blocks` at once
+const PARALLEL_BLOCKS: usize = 8;
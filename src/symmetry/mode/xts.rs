@@ -0,0 +1,154 @@
+// IEEE 1619 https://standards.ieee.org/ieee/1619/4205/
+// NIST SP800-38E https://nvlpubs.nist.gov/nistpubs/Legacy/SP/nistspecialpublication800-38e.pdf
+//
+// The data and tweak ciphers must use different keys, `new` leaves that to the caller
+// while `new_from_key` rejects a key with equal halves.
+
+use super::PARALLEL_BLOCKS;
+use crate::{
+    error::CipherError,
+    symmetry::{
+        aes::{Aes128, Aes256},
+        BlockCipher,
+    },
+    utils::{ct::ct_eq, xor::xor_static},
+};
+
+#[derive(Debug, Clone)]
+pub struct Xts<C> {
+    cipher: C,
+    tweak_cipher: C,
+}
+
+// multiply by x in GF(2^128), little-endian
+fn mul_alpha(tweak: &[u8; 16]) -> [u8; 16] {
+    let t = u128::from_le_bytes(*tweak);
+    let carry = (t >> 127) as u8;
+    let mut r = (t << 1).to_le_bytes();
+    r[0] ^= carry.wrapping_neg() & 0x87;
+    r
+}
+
+impl<C: BlockCipher<16>> Xts<C> {
+    pub fn new(cipher: C, tweak_cipher: C) -> Self {
+        Self {
+            cipher,
+            tweak_cipher,
+        }
+    }
+
+    // the first half keys the data cipher and the second half the tweak cipher
+    pub fn new_from_key(key: &[u8; C::KEY_LENGTH * 2]) -> Result<Self, CipherError>
+    where
+        [u8; C::KEY_LENGTH]: Sized,
+    {
+        let (key1, key2) = key.split_at(C::KEY_LENGTH);
+        if ct_eq(key1, key2) {
+            return Err(CipherError::InvalidKey);
+        }
+        Ok(Self::new(
+            C::new(key1.try_into().unwrap()),
+            C::new(key2.try_into().unwrap()),
+        ))
+    }
+
+    // sector numbers are encoded as 128-bit little-endian tweaks
+    pub fn encrypt_sector(&self, sector: u128, data: &mut [u8]) -> Result<(), CipherError> {
+        self.encrypt(&sector.to_le_bytes(), data)
+    }
+
+    pub fn decrypt_sector(&self, sector: u128, data: &mut [u8]) -> Result<(), CipherError> {
+        self.decrypt(&sector.to_le_bytes(), data)
+    }
+
+    // `data` must be at least one block, a trailing partial block is handled by ciphertext stealing
+    pub fn encrypt(&self, tweak: &[u8; 16], data: &mut [u8]) -> Result<(), CipherError> {
+        if data.len() < 16 {
+            return Err(CipherError::InvalidLength);
+        }
+
+        let (blocks, remainder) = data.as_chunks_mut::<16>();
+        let mut t = *tweak;
+        self.tweak_cipher.encrypt(&mut t);
+
+        if remainder.is_empty() {
+            self.process(t, blocks, C::encrypt_blocks);
+            return Ok(());
+        }
+
+        let (last, blocks) = blocks.split_last_mut().unwrap();
+        t = self.process(t, blocks, C::encrypt_blocks);
+
+        // the last full block borrows its tail from the partial one
+        let t = self.process(t, core::slice::from_mut(last), C::encrypt_blocks);
+        let mut pp = *last;
+        pp[..remainder.len()].copy_from_slice(remainder);
+        remainder.copy_from_slice(&last[..remainder.len()]);
+        self.process(t, core::slice::from_mut(&mut pp), C::encrypt_blocks);
+        *last = pp;
+
+        Ok(())
+    }
+
+    pub fn decrypt(&self, tweak: &[u8; 16], data: &mut [u8]) -> Result<(), CipherError> {
+        if data.len() < 16 {
+            return Err(CipherError::InvalidLength);
+        }
+
+        let (blocks, remainder) = data.as_chunks_mut::<16>();
+        let mut t = *tweak;
+        self.tweak_cipher.encrypt(&mut t);
+
+        if remainder.is_empty() {
+            self.process(t, blocks, C::decrypt_blocks);
+            return Ok(());
+        }
+
+        let (last, blocks) = blocks.split_last_mut().unwrap();
+        t = self.process(t, blocks, C::decrypt_blocks);
+
+        // the last full block was encrypted with the tweak after its own
+        let mut pp = *last;
+        self.process(
+            mul_alpha(&t),
+            core::slice::from_mut(&mut pp),
+            C::decrypt_blocks,
+        );
+        let mut cc = pp;
+        cc[..remainder.len()].copy_from_slice(remainder);
+        remainder.copy_from_slice(&pp[..remainder.len()]);
+        self.process(t, core::slice::from_mut(&mut cc), C::decrypt_blocks);
+        *last = cc;
+
+        Ok(())
+    }
+
+    // xor-encrypt-xor every block with successive tweaks, return the next tweak
+    fn process(
+        &self,
+        mut tweak: [u8; 16],
+        blocks: &mut [[u8; 16]],
+        f: fn(&C, &mut [[u8; 16]]),
+    ) -> [u8; 16] {
+        for chunk in blocks.chunks_mut(PARALLEL_BLOCKS) {
+            let mut tweaks = [[0u8; 16]; PARALLEL_BLOCKS];
+            let tweaks = &mut tweaks[..chunk.len()];
+            for t in tweaks.iter_mut() {
+                *t = tweak;
+                tweak = mul_alpha(&tweak);
+            }
+
+            for (block, t) in chunk.iter_mut().zip(tweaks.iter()) {
+                xor_static(block, t);
+            }
+            f(&self.cipher, chunk);
+            for (block, t) in chunk.iter_mut().zip(tweaks.iter()) {
+                xor_static(block, t);
+            }
+        }
+        tweak
+    }
+}
+
+pub type XtsAes128 = Xts<Aes128>;
+pub type XtsAes256 = Xts<Aes256>;
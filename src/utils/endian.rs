@@ -36,6 +36,7 @@ macro_rules! impl_convert {
 impl_convert!(u8);
 impl_convert!(u16);
 impl_convert!(u32);
+impl_convert!(u64);
 
 pub(crate) fn assert_len<const N: usize, T: SingleEndianConvertion>(s: &[T]) -> &[T; N] {
     s.try_into().unwrap()
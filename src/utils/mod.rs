@@ -1,3 +1,4 @@
+pub(crate) mod ct;
 pub(crate) mod endian;
 pub mod hex;
 pub(crate) mod xor;
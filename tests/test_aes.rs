@@ -1,49 +1,168 @@
 use aes::{
     cipher::{BlockDecrypt, BlockEncrypt, KeyInit},
-    Aes128 as StdAes128,
+    Aes128 as StdAes128, Aes192 as StdAes192, Aes256 as StdAes256,
 };
 use primit::{
-    rng::{FastRng, Rng},
-    symmetry::aes::Aes128,
+    rng::{aes::BlockCipherRng, FastRng, Rng},
+    symmetry::{
+        aes::{Aes128, Aes192, Aes256},
+        BlockCipher,
+    },
+    utils::hex::decode_fix,
 };
 
+macro_rules! test_cipher {
+    ($encrypt:ident, $decrypt:ident, $cipher:ident, $std_cipher:ident, $key_size:literal) => {
+        #[test]
+        fn $encrypt() {
+            let mut rng = FastRng::new_from_seed(&[0u8; 32]);
+            let mut input = [0u8; 16];
+            let mut key = [0u8; $key_size];
+            for _ in 0..1000 {
+                rng.fill_bytes(&mut input);
+                rng.fill_bytes(&mut key);
+                let mut output = input;
+
+                let cipher = $cipher::new(&key);
+                cipher.encrypt(&mut output);
+
+                let std_cipher = $std_cipher::new_from_slice(&key).unwrap();
+                std_cipher.decrypt_block((&mut output).into());
+
+                assert_eq!(input, output);
+            }
+        }
+
+        #[test]
+        fn $decrypt() {
+            let mut rng = FastRng::new_from_seed(&[0u8; 32]);
+            let mut input = [0u8; 16];
+            let mut key = [0u8; $key_size];
+            for _ in 0..1000 {
+                let mut output = input;
+
+                let std_cipher = $std_cipher::new_from_slice(&key).unwrap();
+                std_cipher.encrypt_block((&mut output).into());
+
+                let cipher = $cipher::new(&key);
+                cipher.decrypt(&mut output);
+
+                assert_eq!(input, output);
+
+                rng.fill_bytes(&mut input);
+                rng.fill_bytes(&mut key);
+            }
+        }
+    };
+}
+
+test_cipher!(test_encrypt, test_decrypt, Aes128, StdAes128, 16);
+test_cipher!(test_encrypt_192, test_decrypt_192, Aes192, StdAes192, 24);
+test_cipher!(test_encrypt_256, test_decrypt_256, Aes256, StdAes256, 32);
+
+// FIPS-197 Appendix C
+const PLAINTEXT: &[u8; 32] = b"00112233445566778899aabbccddeeff";
+
+#[test]
+fn test_fips197_aes128() {
+    let cipher = Aes128::new(&decode_fix::<16>(b"000102030405060708090a0b0c0d0e0f").unwrap());
+    let mut block = decode_fix::<16>(PLAINTEXT).unwrap();
+
+    cipher.encrypt(&mut block);
+    assert_eq!(
+        block,
+        decode_fix::<16>(b"69c4e0d86a7b0430d8cdb78070b4c55a").unwrap()
+    );
+
+    cipher.decrypt(&mut block);
+    assert_eq!(block, decode_fix::<16>(PLAINTEXT).unwrap());
+}
+
+#[test]
+fn test_fips197_aes192() {
+    let cipher = Aes192::new(
+        &decode_fix::<24>(b"000102030405060708090a0b0c0d0e0f1011121314151617").unwrap(),
+    );
+    let mut block = decode_fix::<16>(PLAINTEXT).unwrap();
+
+    cipher.encrypt(&mut block);
+    assert_eq!(
+        block,
+        decode_fix::<16>(b"dda97ca4864cdfe06eaf70a0ec0d7191").unwrap()
+    );
+
+    cipher.decrypt(&mut block);
+    assert_eq!(block, decode_fix::<16>(PLAINTEXT).unwrap());
+}
+
 #[test]
-fn test_encrypt() {
-    let mut rng = FastRng::new_from_seed(&[0u8; 32]);
-    let mut input = [0u8; 16];
-    let mut key = [0u8; 16];
-    for _ in 0..1000 {
-        rng.fill_bytes(&mut input);
-        rng.fill_bytes(&mut key);
-        let mut output = input;
-
-        let cipher = Aes128::new(&key);
-        cipher.encrypt(&mut output);
-
-        let std_cipher = StdAes128::new_from_slice(&key).unwrap();
-        std_cipher.decrypt_block((&mut output).into());
-
-        assert_eq!(input, output);
+fn test_fips197_aes256() {
+    let cipher = Aes256::new(
+        &decode_fix::<32>(b"000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f")
+            .unwrap(),
+    );
+    let mut block = decode_fix::<16>(PLAINTEXT).unwrap();
+
+    cipher.encrypt(&mut block);
+    assert_eq!(
+        block,
+        decode_fix::<16>(b"8ea2b7ca516745bfeafc49904b496089").unwrap()
+    );
+
+    cipher.decrypt(&mut block);
+    assert_eq!(block, decode_fix::<16>(PLAINTEXT).unwrap());
+}
+
+fn check_blocks<C: BlockCipher<16>>(cipher: &C) {
+    // cover both the parallel path and the remainder
+    for n in 0..19 {
+        let input: [[u8; 16]; 19] = core::array::from_fn(|i| [i as u8; 16]);
+        let mut blocks = input;
+
+        cipher.encrypt_blocks(&mut blocks[..n]);
+        for (i, (block, expected)) in blocks.iter().zip(input).enumerate() {
+            let mut expected = expected;
+            if i < n {
+                cipher.encrypt(&mut expected);
+            }
+            assert_eq!(block, &expected);
+        }
+
+        cipher.decrypt_blocks(&mut blocks[..n]);
+        assert_eq!(blocks, input);
     }
 }
 
 #[test]
-fn test_decrypt() {
-    let mut rng = FastRng::new_from_seed(&[0u8; 32]);
-    let mut input = [0u8; 16];
-    let mut key = [0u8; 16];
-    for _ in 0..1000 {
-        let mut output = input;
+fn test_block_cipher() {
+    check_blocks(&<Aes128 as BlockCipher<16>>::new(&[1u8; 16]));
+    check_blocks(&<Aes192 as BlockCipher<16>>::new(&[2u8; 24]));
+    check_blocks(&<Aes256 as BlockCipher<16>>::new(&[3u8; 32]));
+}
+
+#[test]
+fn test_block_cipher_rng() {
+    let seed: [u8; 32] = core::array::from_fn(|i| i as u8);
 
-        let std_cipher = StdAes128::new_from_slice(&key).unwrap();
-        std_cipher.encrypt_block((&mut output).into());
+    fn output<R: Rng>(seed: &[u8; 32]) -> [u8; 100] {
+        let mut output = [0u8; 100];
+        R::new_from_seed(seed).fill_bytes(&mut output);
+        output
+    }
 
-        let cipher = Aes128::new(&key);
-        cipher.decrypt(&mut output);
+    let aes128 = output::<BlockCipherRng<Aes128>>(&seed);
+    let aes192 = output::<BlockCipherRng<Aes192>>(&seed);
+    let aes256 = output::<BlockCipherRng<Aes256>>(&seed);
 
-        assert_eq!(input, output);
+    // every key size takes its own key from the seed
+    assert_ne!(aes128, aes192);
+    assert_ne!(aes128, aes256);
+    assert_ne!(aes192, aes256);
+    assert_eq!(aes256, output::<BlockCipherRng<Aes256>>(&seed));
 
-        rng.fill_bytes(&mut input);
-        rng.fill_bytes(&mut key);
+    // the counter advances between blocks
+    for output in [aes128, aes192, aes256] {
+        let (blocks, _) = output.as_chunks::<16>();
+        assert!(blocks.windows(2).all(|w| w[0] != w[1]));
     }
 }
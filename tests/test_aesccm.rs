@@ -0,0 +1,183 @@
+use ccm::{
+    aead::AeadInPlace,
+    consts::{U10, U13, U16, U4, U7, U8},
+    KeyInit,
+};
+use primit::{
+    aead::aesccm::AESCCM,
+    error::AeadError,
+    rng::{FastRng, Rng},
+    symmetry::aes::Aes128,
+    utils::hex::decode_fix,
+};
+
+// RFC3610 8, packet vector #1
+#[test]
+fn test_aesccm_rfc3610() {
+    let alg = AESCCM::<8, 13>::new(&decode_fix::<16>(b"c0c1c2c3c4c5c6c7c8c9cacbcccdcecf").unwrap());
+    let nonce = decode_fix::<13>(b"00000003020100a0a1a2a3a4a5").unwrap();
+    let ad = decode_fix::<8>(b"0001020304050607").unwrap();
+    let plaintext = decode_fix::<23>(b"08090a0b0c0d0e0f101112131415161718191a1b1c1d1e").unwrap();
+
+    let mut data = plaintext;
+    let tag = alg.encrypt(&nonce, &ad, &mut data).unwrap();
+    assert_eq!(
+        data,
+        decode_fix::<23>(b"588c979a61c663d2f066d0c2c0f989806d5f6b61dac384").unwrap()
+    );
+    assert_eq!(tag, decode_fix::<8>(b"17e8d12cfdf926e0").unwrap());
+
+    alg.decrypt(&nonce, &ad, &mut data, &tag).unwrap();
+    assert_eq!(data, plaintext);
+}
+
+// NIST SP800-38C C.1, C.2
+#[test]
+fn test_aesccm_sp800_38c() {
+    let key = decode_fix::<16>(b"404142434445464748494a4b4c4d4e4f").unwrap();
+
+    let alg = AESCCM::<4, 7>::new(&key);
+    let nonce = decode_fix::<7>(b"10111213141516").unwrap();
+    let mut data = decode_fix::<4>(b"20212223").unwrap();
+    let tag = alg
+        .encrypt(
+            &nonce,
+            &decode_fix::<8>(b"0001020304050607").unwrap(),
+            &mut data,
+        )
+        .unwrap();
+    assert_eq!(data, decode_fix::<4>(b"7162015b").unwrap());
+    assert_eq!(tag, decode_fix::<4>(b"4dac255d").unwrap());
+
+    let alg = AESCCM::<6, 8>::new(&key);
+    let nonce = decode_fix::<8>(b"1011121314151617").unwrap();
+    let mut data = decode_fix::<16>(b"202122232425262728292a2b2c2d2e2f").unwrap();
+    let tag = alg
+        .encrypt(
+            &nonce,
+            &decode_fix::<16>(b"000102030405060708090a0b0c0d0e0f").unwrap(),
+            &mut data,
+        )
+        .unwrap();
+    assert_eq!(
+        data,
+        decode_fix::<16>(b"d2a1f0e051ea5f62081a7792073d593d").unwrap()
+    );
+    assert_eq!(tag, decode_fix::<6>(b"1fc64fbfaccd").unwrap());
+}
+
+// the associated data length takes 6 bytes past 0xfeff, generated by pyca/cryptography
+#[test]
+fn test_aesccm_long_ad() {
+    let alg =
+        AESCCM::<16, 12>::new(&decode_fix::<16>(b"404142434445464748494a4b4c4d4e4f").unwrap());
+    let nonce = decode_fix::<12>(b"101112131415161718191a1b").unwrap();
+
+    let tag = alg.encrypt(&nonce, &[0u8; 70000], &mut []).unwrap();
+    assert_eq!(
+        tag,
+        decode_fix::<16>(b"945c1f149f94dd8bbd5cab4e2589abe1").unwrap()
+    );
+}
+
+#[test]
+fn test_aesccm_too_long() {
+    // the message length must fit in 15 - 13 = 2 bytes
+    let alg = AESCCM::<16, 13>::new(&[0u8; 16]);
+    let mut data = [0u8; 0x10000];
+    assert_eq!(
+        alg.encrypt(&[0u8; 13], &[], &mut data),
+        Err(AeadError::InvalidLength)
+    );
+    assert!(alg.encrypt(&[0u8; 13], &[], &mut data[1..]).is_ok());
+
+    // nothing is decrypted before the length is checked
+    let mut data = [7u8; 0x10000];
+    assert_eq!(
+        alg.decrypt(&[0u8; 13], &[], &mut data, &[0u8; 16]),
+        Err(AeadError::InvalidLength)
+    );
+    assert_eq!(data, [7u8; 0x10000]);
+}
+
+#[test]
+fn test_aesccm_bad_mac() {
+    let alg = AESCCM::<8, 13>::new(&[1u8; 16]);
+    let nonce = [3u8; 13];
+
+    let mut data = [7u8; 40];
+    let tag = alg.encrypt(&nonce, b"ad", &mut data).unwrap();
+
+    let mut tampered = data;
+    tampered[39] ^= 1;
+    assert_eq!(
+        alg.decrypt(&nonce, b"ad", &mut tampered, &tag),
+        Err(AeadError::BadMac)
+    );
+    assert_eq!(tampered, [0u8; 40]);
+
+    let mut bad_tag = tag;
+    bad_tag[7] ^= 1;
+    assert_eq!(
+        alg.decrypt(&nonce, b"ad", &mut data.clone(), &bad_tag),
+        Err(AeadError::BadMac)
+    );
+    assert_eq!(
+        alg.decrypt(&nonce, b"", &mut data.clone(), &tag),
+        Err(AeadError::BadMac)
+    );
+}
+
+macro_rules! fuzz_ccm {
+    ($name:ident, $tag:literal, $nonce:literal, $std_tag:ty, $std_nonce:ty) => {
+        #[test]
+        fn $name() {
+            type StdCcm = ccm::Ccm<aes::Aes128, $std_tag, $std_nonce>;
+
+            let mut rng = FastRng::new_from_seed(&[0u8; 32]);
+            let mut key = [0u8; 16];
+            let mut nonce = [0u8; $nonce];
+            let mut ad = [0u8; 64];
+            let mut text = [0u8; 100];
+
+            for i in 0..200 {
+                rng.fill_bytes(&mut key);
+                rng.fill_bytes(&mut nonce);
+                rng.fill_bytes(&mut ad);
+                rng.fill_bytes(&mut text);
+                let ad = &ad[..i % 64];
+                let text = &text[..i % 100];
+
+                let alg = AESCCM::<$tag, $nonce>::new(&key);
+                let mut data = text.to_vec();
+                let tag = alg.encrypt(&nonce, ad, &mut data).unwrap();
+
+                let mut std_data = text.to_vec();
+                let std_tag = StdCcm::new_from_slice(&key)
+                    .unwrap()
+                    .encrypt_in_place_detached(&nonce.into(), ad, &mut std_data)
+                    .unwrap();
+                assert_eq!(data, std_data);
+                assert_eq!(tag.as_slice(), std_tag.as_slice());
+
+                alg.decrypt(&nonce, ad, &mut data, &tag).unwrap();
+                assert_eq!(data, text);
+            }
+        }
+    };
+}
+
+fuzz_ccm!(test_aesccm_fuzz_16_13, 16, 13, U16, U13);
+fuzz_ccm!(test_aesccm_fuzz_8_7, 8, 7, U8, U7);
+fuzz_ccm!(test_aesccm_fuzz_4_10, 4, 10, U4, U10);
+
+#[test]
+fn test_aesccm_from_cipher() {
+    let key = [5u8; 16];
+    let mut a = [9u8; 33];
+    let mut b = a;
+    let tag_a = AESCCM::<12, 11>::new(&key).encrypt(&[1u8; 11], b"ad", &mut a);
+    let tag_b = AESCCM::<12, 11>::from_cipher(Aes128::new(&key)).encrypt(&[1u8; 11], b"ad", &mut b);
+    assert_eq!(tag_a, tag_b);
+    assert_eq!(a, b);
+}
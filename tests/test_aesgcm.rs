@@ -1,6 +1,11 @@
-use aes_gcm::{aead::AeadInPlace, aes::cipher::generic_array::GenericArray, Aes128Gcm, KeyInit};
+use aes_gcm::{
+    aead::AeadInPlace, aes::cipher::generic_array::GenericArray, Aes128Gcm, Aes256Gcm, KeyInit,
+};
 use primit::{
-    aead::{aesgcm::AESGCM, Aead, Decryptor, Encryptor},
+    aead::{
+        aesgcm::{AES256GCM, AESGCM},
+        Aead, Decryptor, Encryptor,
+    },
     rng::{FastRng, Rng},
     utils::hex::decode_fix,
 };
@@ -103,3 +108,81 @@ fn test_aesgcm_fuzz() {
         tester(&key, &nonce, &ad, &text);
     }
 }
+
+#[test]
+fn test_aes256gcm() {
+    // Test  Case  13
+    let key = [0u8; 32];
+    let iv = [0u8; 12];
+    let mut text = [0u8; 0];
+
+    let alg = AES256GCM::new(&key);
+    let tag = alg.encryptor(&iv, &[]).finalize(&mut text);
+    assert_eq!(
+        tag,
+        decode_fix::<16>(b"530f8afbc74536b9a963b4f1c4cb738b").unwrap()
+    );
+
+    // Test  Case  14
+    let mut text = [0u8; 16];
+
+    let tag = alg.encryptor(&iv, &[]).finalize(&mut text);
+    assert_eq!(
+        text,
+        decode_fix::<16>(b"cea7403d4d606b6e074ec5d3baf39d18").unwrap()
+    );
+    assert_eq!(
+        tag,
+        decode_fix::<16>(b"d0d1c8a799996bf0265b98b5d48ab919").unwrap()
+    );
+
+    alg.decryptor(&iv, &[]).finalize(&mut text, &tag).unwrap();
+    assert_eq!(text, [0u8; 16]);
+
+    let mut bad_tag = tag;
+    bad_tag[0] ^= 1;
+    assert!(alg
+        .decryptor(&iv, &[])
+        .finalize(&mut text, &bad_tag)
+        .is_err());
+}
+
+#[test]
+fn test_aes256gcm_fuzz() {
+    fn tester(key: &[u8; 32], nonce: &[u8; 12], ad: &[u8], text: &[u8]) {
+        let alg = AES256GCM::new(key);
+        let std_alg = Aes256Gcm::new(GenericArray::from_slice(key));
+
+        let mut data = text.to_vec();
+        let tag = alg.encryptor(nonce, ad).finalize(&mut data);
+
+        let mut std_data = text.to_vec();
+        let std_tag = std_alg
+            .encrypt_in_place_detached(GenericArray::from_slice(nonce), ad, &mut std_data)
+            .unwrap();
+
+        assert_eq!(&data, &std_data);
+        assert_eq!(tag.as_slice(), std_tag.as_slice());
+
+        alg.decryptor(nonce, ad).finalize(&mut data, &tag).unwrap();
+        assert_eq!(data, text);
+    }
+
+    let mut rng = FastRng::new_from_seed(&[0u8; 32]);
+
+    for _ in 0..1000 {
+        let mut key = [0u8; 32];
+        let mut nonce = [0u8; 12];
+        let mut ad = [0u8; 64];
+        let mut text = [0u8; 64];
+        rng.fill_bytes(&mut key);
+        rng.fill_bytes(&mut nonce);
+        rng.fill_bytes(&mut ad);
+        rng.fill_bytes(&mut text);
+
+        tester(&key, &nonce, &ad[..0], &text[..47]);
+        tester(&key, &nonce, &ad[..47], &text[..0]);
+        tester(&key, &nonce, &ad[..47], &text[..32]);
+        tester(&key, &nonce, &ad, &text);
+    }
+}
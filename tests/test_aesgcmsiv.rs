@@ -0,0 +1,145 @@
+use aes_gcm_siv::{aead::AeadInPlace, Aes128GcmSiv, Aes256GcmSiv, KeyInit};
+use primit::{
+    aead::aesgcmsiv::{AES256GCMSIV, AESGCMSIV},
+    error::AeadError,
+    rng::{FastRng, Rng},
+    utils::hex::decode_fix,
+};
+
+// RFC8452 C.1
+#[test]
+fn test_aesgcmsiv() {
+    let alg = AESGCMSIV::new(&decode_fix::<16>(b"01000000000000000000000000000000").unwrap());
+    let nonce = decode_fix::<12>(b"030000000000000000000000").unwrap();
+
+    let mut data = [0u8; 0];
+    let tag = alg.encrypt(&nonce, &[], &mut data).unwrap();
+    assert_eq!(
+        tag,
+        decode_fix::<16>(b"dc20e2d83f25705bb49e439eca56de25").unwrap()
+    );
+    alg.decrypt(&nonce, &[], &mut data, &tag).unwrap();
+
+    let mut data = decode_fix::<8>(b"0100000000000000").unwrap();
+    let tag = alg.encrypt(&nonce, &[], &mut data).unwrap();
+    assert_eq!(data, decode_fix::<8>(b"b5d839330ac7b786").unwrap());
+    assert_eq!(
+        tag,
+        decode_fix::<16>(b"578782fff6013b815b287c22493a364c").unwrap()
+    );
+    alg.decrypt(&nonce, &[], &mut data, &tag).unwrap();
+    assert_eq!(data, decode_fix::<8>(b"0100000000000000").unwrap());
+
+    let ad = [1u8];
+    let mut data = decode_fix::<16>(b"02000000000000000000000000000000").unwrap();
+    let tag = alg.encrypt(&nonce, &ad, &mut data).unwrap();
+    assert_eq!(
+        data,
+        decode_fix::<16>(b"e2b0c5da79a901c1745f700525cb335b").unwrap()
+    );
+    assert_eq!(
+        tag,
+        decode_fix::<16>(b"8f8936ec039e4e4bb97ebd8c4457441f").unwrap()
+    );
+    alg.decrypt(&nonce, &ad, &mut data, &tag).unwrap();
+    assert_eq!(
+        data,
+        decode_fix::<16>(b"02000000000000000000000000000000").unwrap()
+    );
+}
+
+// RFC8452 C.2
+#[test]
+fn test_aes256gcmsiv() {
+    let alg = AES256GCMSIV::new(
+        &decode_fix::<32>(b"0100000000000000000000000000000000000000000000000000000000000000")
+            .unwrap(),
+    );
+    let nonce = decode_fix::<12>(b"030000000000000000000000").unwrap();
+
+    let tag = alg.encrypt(&nonce, &[], &mut []).unwrap();
+    assert_eq!(
+        tag,
+        decode_fix::<16>(b"07f5f4169bbf55a8400cd47ea6fd400f").unwrap()
+    );
+
+    let mut data = decode_fix::<8>(b"0100000000000000").unwrap();
+    let tag = alg.encrypt(&nonce, &[], &mut data).unwrap();
+    assert_eq!(data, decode_fix::<8>(b"c2ef328e5c71c83b").unwrap());
+    assert_eq!(
+        tag,
+        decode_fix::<16>(b"843122130f7364b761e0b97427e3df28").unwrap()
+    );
+}
+
+#[test]
+fn test_aesgcmsiv_bad_mac() {
+    let alg = AESGCMSIV::new(&[1u8; 16]);
+    let nonce = [3u8; 12];
+
+    let mut data = [7u8; 40];
+    let tag = alg.encrypt(&nonce, b"ad", &mut data).unwrap();
+
+    let mut tampered = data;
+    tampered[39] ^= 1;
+    assert_eq!(
+        alg.decrypt(&nonce, b"ad", &mut tampered, &tag),
+        Err(AeadError::BadMac)
+    );
+    assert_eq!(tampered, [0u8; 40]);
+
+    let mut bad_tag = tag;
+    bad_tag[0] ^= 1;
+    assert_eq!(
+        alg.decrypt(&nonce, b"ad", &mut data.clone(), &bad_tag),
+        Err(AeadError::BadMac)
+    );
+    assert_eq!(
+        alg.decrypt(&nonce, b"da", &mut data.clone(), &tag),
+        Err(AeadError::BadMac)
+    );
+}
+
+#[test]
+fn test_aesgcmsiv_fuzz() {
+    let mut rng = FastRng::new_from_seed(&[0u8; 32]);
+    let mut key = [0u8; 32];
+    let mut nonce = [0u8; 12];
+    let mut ad = [0u8; 64];
+    let mut text = [0u8; 100];
+
+    for i in 0..200 {
+        rng.fill_bytes(&mut key);
+        rng.fill_bytes(&mut nonce);
+        rng.fill_bytes(&mut ad);
+        rng.fill_bytes(&mut text);
+        let ad = &ad[..i % 64];
+        let text = &text[..i % 100];
+
+        let mut data = text.to_vec();
+        let tag = AESGCMSIV::new(key[..16].try_into().unwrap())
+            .encrypt(&nonce, ad, &mut data)
+            .unwrap();
+        let mut std_data = text.to_vec();
+        let std_tag = Aes128GcmSiv::new_from_slice(&key[..16])
+            .unwrap()
+            .encrypt_in_place_detached(&nonce.into(), ad, &mut std_data)
+            .unwrap();
+        assert_eq!(data, std_data);
+        assert_eq!(tag.as_slice(), std_tag.as_slice());
+
+        let alg = AES256GCMSIV::new(&key);
+        let mut data = text.to_vec();
+        let tag = alg.encrypt(&nonce, ad, &mut data).unwrap();
+        let mut std_data = text.to_vec();
+        let std_tag = Aes256GcmSiv::new_from_slice(&key)
+            .unwrap()
+            .encrypt_in_place_detached(&nonce.into(), ad, &mut std_data)
+            .unwrap();
+        assert_eq!(data, std_data);
+        assert_eq!(tag.as_slice(), std_tag.as_slice());
+
+        alg.decrypt(&nonce, ad, &mut data, &tag).unwrap();
+        assert_eq!(data, text);
+    }
+}
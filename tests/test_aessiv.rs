@@ -0,0 +1,134 @@
+use primit::{
+    aead::aessiv::{AES256SIV, AESSIV},
+    error::AeadError,
+    utils::hex::decode_fix,
+};
+
+// RFC5297 A.1, deterministic authenticated encryption
+#[test]
+fn test_aessiv_deterministic() {
+    let alg = AESSIV::new(
+        &decode_fix::<32>(b"fffefdfcfbfaf9f8f7f6f5f4f3f2f1f0f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff")
+            .unwrap(),
+    );
+    let ad = decode_fix::<24>(b"101112131415161718191a1b1c1d1e1f2021222324252627").unwrap();
+    let plaintext = decode_fix::<14>(b"112233445566778899aabbccddee").unwrap();
+
+    let mut data = plaintext;
+    let siv = alg.encrypt(&[&ad], &mut data);
+    assert_eq!(
+        siv,
+        decode_fix::<16>(b"85632d07c6e8f37f950acd320a2ecc93").unwrap()
+    );
+    assert_eq!(
+        data,
+        decode_fix::<14>(b"40c02b9690c4dc04daef7f6afe5c").unwrap()
+    );
+
+    alg.decrypt(&[&ad], &mut data, &siv).unwrap();
+    assert_eq!(data, plaintext);
+}
+
+// RFC5297 A.2, nonce-based authenticated encryption
+#[test]
+fn test_aessiv_nonce() {
+    let alg = AESSIV::new(
+        &decode_fix::<32>(b"7f7e7d7c7b7a79787776757473727170404142434445464748494a4b4c4d4e4f")
+            .unwrap(),
+    );
+    let ad1 = decode_fix::<40>(
+        b"00112233445566778899aabbccddeeffdeaddadadeaddadaffeeddccbbaa99887766554433221100",
+    )
+    .unwrap();
+    let ad2 = decode_fix::<10>(b"102030405060708090a0").unwrap();
+    let nonce = decode_fix::<16>(b"09f911029d74e35bd84156c5635688c0").unwrap();
+    let plaintext = decode_fix::<47>(
+        b"7468697320697320736f6d6520706c61696e7465787420746f20656e6372797074207573696e67205349562d414553",
+    )
+    .unwrap();
+
+    let mut data = plaintext;
+    let siv = alg.encrypt(&[&ad1, &ad2, &nonce], &mut data);
+    assert_eq!(
+        siv,
+        decode_fix::<16>(b"7bdb6e3b432667eb06f4d14bff2fbd0f").unwrap()
+    );
+    assert_eq!(
+        data,
+        decode_fix::<47>(
+            b"cb900f2fddbe404326601965c889bf17dba77ceb094fa663b7a3f748ba8af829ea64ad544a272e9c485b62a3fd5c0d",
+        )
+        .unwrap()
+    );
+
+    alg.decrypt(&[&ad1, &ad2, &nonce], &mut data, &siv).unwrap();
+    assert_eq!(data, plaintext);
+}
+
+// generated by pyca/cryptography
+#[test]
+fn test_aes256siv() {
+    let mut key = [0u8; 64];
+    key.iter_mut().enumerate().for_each(|(i, x)| *x = i as u8);
+    let alg = AES256SIV::new(&key);
+
+    let mut plaintext = [0u8; 40];
+    plaintext
+        .iter_mut()
+        .enumerate()
+        .for_each(|(i, x)| *x = i as u8);
+    let ad: &[&[u8]] = &[b"header", b"", &plaintext[..16]];
+
+    let mut data = plaintext;
+    let siv = alg.encrypt(ad, &mut data);
+    assert_eq!(
+        siv,
+        decode_fix::<16>(b"a3793c55aa344e3f7fb059ccca502c36").unwrap()
+    );
+    assert_eq!(
+        data,
+        decode_fix::<40>(
+            b"4c5a6a93c969e470001b3cf3692ac1ff146bc971e22632dd9f8e73c98259151f47ed237bb4403123"
+        )
+        .unwrap()
+    );
+    alg.decrypt(ad, &mut data, &siv).unwrap();
+    assert_eq!(data, plaintext);
+
+    let siv = alg.encrypt(&[b"header"], &mut []);
+    assert_eq!(
+        siv,
+        decode_fix::<16>(b"00cf07cfa49e636ab8210baa1ad8d3b0").unwrap()
+    );
+    alg.decrypt(&[b"header"], &mut [], &siv).unwrap();
+}
+
+#[test]
+fn test_aessiv_bad_mac() {
+    let alg = AESSIV::new(&[1u8; 32]);
+    let plaintext = *b"deterministic authenticated encryption";
+
+    let mut data = plaintext;
+    let siv = alg.encrypt(&[b"ad"], &mut data);
+
+    let mut forged = data;
+    assert_eq!(
+        alg.decrypt(&[b"AD"], &mut forged, &siv),
+        Err(AeadError::BadMac)
+    );
+    assert_eq!(forged, [0u8; 38]);
+
+    // the order of associated data components matters
+    let mut forged = data;
+    assert_eq!(
+        alg.decrypt(&[b"ad", b""], &mut forged, &siv),
+        Err(AeadError::BadMac)
+    );
+
+    let mut bad_siv = siv;
+    bad_siv[0] ^= 1;
+    assert_eq!(
+        alg.decrypt(&[b"ad"], &mut data, &bad_siv),
+        Err(AeadError::BadMac)
+    );
+}
@@ -0,0 +1,148 @@
+use aes::cipher::{BlockDecryptMut, BlockEncryptMut, KeyIvInit};
+use primit::{
+    error::CipherError,
+    rng::{FastRng, Rng},
+    symmetry::{
+        aes::{Aes128, Aes256},
+        mode::cbc,
+    },
+    utils::hex::decode_fix,
+};
+
+type StdAes128Cbc = ::cbc::Encryptor<aes::Aes128>;
+type StdAes128CbcDec = ::cbc::Decryptor<aes::Aes128>;
+
+// NIST SP800-38A F.2
+const PLAINTEXT: &[u8; 128] = b"6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e5130c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710";
+const IV: &[u8; 32] = b"000102030405060708090a0b0c0d0e0f";
+
+#[test]
+fn test_cbc_aes128() {
+    let cipher = Aes128::new(&decode_fix::<16>(b"2b7e151628aed2a6abf7158809cf4f3c").unwrap());
+    let iv = decode_fix::<16>(IV).unwrap();
+    let mut data = decode_fix::<64>(PLAINTEXT).unwrap();
+
+    cbc::encrypt(&cipher, &iv, &mut data).unwrap();
+    assert_eq!(data, decode_fix::<64>(b"7649abac8119b246cee98e9b12e9197d5086cb9b507219ee95db113a917678b273bed6b8e3c1743b7116e69e222295163ff1caa1681fac09120eca307586e1a7").unwrap());
+
+    cbc::decrypt(&cipher, &iv, &mut data).unwrap();
+    assert_eq!(data, decode_fix::<64>(PLAINTEXT).unwrap());
+}
+
+#[test]
+fn test_cbc_aes256() {
+    let cipher = Aes256::new(
+        &decode_fix::<32>(b"603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4")
+            .unwrap(),
+    );
+    let iv = decode_fix::<16>(IV).unwrap();
+    let mut data = decode_fix::<64>(PLAINTEXT).unwrap();
+
+    cbc::encrypt(&cipher, &iv, &mut data).unwrap();
+    assert_eq!(data, decode_fix::<64>(b"f58c4c04d6e5f1ba779eabfb5f7bfbd69cfc4e967edb808d679f777bc6702c7d39f23369a9d9bacfa530e26304231461b2eb05e2c39be9fcda6c19078c6a9d1b").unwrap());
+
+    cbc::decrypt(&cipher, &iv, &mut data).unwrap();
+    assert_eq!(data, decode_fix::<64>(PLAINTEXT).unwrap());
+}
+
+#[test]
+fn test_cbc_padded() {
+    let cipher = Aes128::new(&decode_fix::<16>(b"2b7e151628aed2a6abf7158809cf4f3c").unwrap());
+    let iv = decode_fix::<16>(IV).unwrap();
+
+    let mut buffer = [0u8; 16];
+    buffer[..6].copy_from_slice(b"primit");
+    assert_eq!(cbc::encrypt_padded(&cipher, &iv, &mut buffer, 6), Ok(16));
+    assert_eq!(
+        buffer,
+        decode_fix::<16>(b"24b440726ad0e62819ed85c978ab002f").unwrap()
+    );
+    assert_eq!(cbc::decrypt_padded(&cipher, &iv, &mut buffer), Ok(6));
+    assert_eq!(&buffer[..6], b"primit");
+
+    // a full block of padding
+    let mut buffer = [0u8; 32];
+    assert_eq!(cbc::encrypt_padded(&cipher, &iv, &mut buffer, 16), Ok(32));
+    assert_eq!(
+        buffer,
+        decode_fix::<32>(b"50fe67cc996d32b6da0937e99bafec603a471a730e06602f7791e02e09928309")
+            .unwrap()
+    );
+    assert_eq!(cbc::decrypt_padded(&cipher, &iv, &mut buffer), Ok(16));
+    assert_eq!(&buffer[..16], &[0u8; 16]);
+
+    // no room for padding
+    assert_eq!(
+        cbc::encrypt_padded(&cipher, &iv, &mut [0u8; 16], 16),
+        Err(CipherError::InvalidLength)
+    );
+}
+
+#[test]
+fn test_cbc_invalid() {
+    let cipher = Aes128::new(&[0u8; 16]);
+    let iv = [0u8; 16];
+
+    assert_eq!(
+        cbc::encrypt(&cipher, &iv, &mut [0u8; 17]),
+        Err(CipherError::InvalidLength)
+    );
+    assert_eq!(
+        cbc::decrypt_padded(&cipher, &iv, &mut []),
+        Err(CipherError::InvalidLength)
+    );
+    assert_eq!(
+        cbc::decrypt_padded(&cipher, &iv, &mut [0u8; 31]),
+        Err(CipherError::InvalidLength)
+    );
+
+    // every corrupted last byte must be rejected
+    for n in [0u8, 2, 17, 0xff] {
+        let mut block = [1u8; 16];
+        block[15] = n;
+        cbc::encrypt(&cipher, &iv, &mut block).unwrap();
+        assert_eq!(
+            cbc::decrypt_padded(&cipher, &iv, &mut block),
+            Err(CipherError::InvalidPadding)
+        );
+    }
+
+    // corrupted padding byte in the middle
+    let mut block = [3u8; 16];
+    block[14] = 2;
+    cbc::encrypt(&cipher, &iv, &mut block).unwrap();
+    assert_eq!(
+        cbc::decrypt_padded(&cipher, &iv, &mut block),
+        Err(CipherError::InvalidPadding)
+    );
+}
+
+#[test]
+fn test_cbc_fuzz() {
+    let mut rng = FastRng::new_from_seed(&[0u8; 32]);
+    let mut key = [0u8; 16];
+    let mut iv = [0u8; 16];
+
+    for i in 0..200 {
+        rng.fill_bytes(&mut key);
+        rng.fill_bytes(&mut iv);
+        let mut data = [0u8; 16 * 19];
+        rng.fill_bytes(&mut data);
+        let data = &mut data[..i % 20 * 16];
+        let mut std_data = data.to_vec();
+
+        cbc::encrypt(&Aes128::new(&key), &iv, data).unwrap();
+        let mut std_cipher = StdAes128Cbc::new(&key.into(), &iv.into());
+        for block in std_data.chunks_mut(16) {
+            std_cipher.encrypt_block_mut(block.into());
+        }
+        assert_eq!(data, std_data);
+
+        cbc::decrypt(&Aes128::new(&key), &iv, data).unwrap();
+        let mut std_cipher = StdAes128CbcDec::new(&key.into(), &iv.into());
+        for block in std_data.chunks_mut(16) {
+            std_cipher.decrypt_block_mut(block.into());
+        }
+        assert_eq!(data, std_data);
+    }
+}
@@ -1,8 +1,14 @@
-use chacha20::cipher::{KeyIvInit, StreamCipher};
+use chacha20::cipher::{KeyIvInit, StreamCipher, StreamCipherSeek};
 use primit::{
+    error::CipherError,
     rng::{FastRng, Rng},
-    symmetry::chacha::ChaCha20,
+    symmetry::chacha::{
+        hchacha20, ChaCha12, ChaCha12Legacy, ChaCha20, ChaCha20Legacy, ChaCha8, ChaCha8Legacy,
+        XChaCha20,
+    },
+    utils::hex::decode_fix,
 };
+use rand_chacha::rand_core::{RngCore, SeedableRng};
 
 #[test]
 fn test_chacha20() {
@@ -27,3 +33,369 @@ fn test_chacha20() {
         rng.fill_bytes(&mut nonce);
     }
 }
+
+// RFC8439 2.3.2
+#[test]
+fn test_chacha20_block() {
+    let key = decode_fix::<32>(b"000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f")
+        .unwrap();
+    let nonce = decode_fix::<12>(b"000000090000004a00000000").unwrap();
+
+    let mut block = [0u8; 64];
+    let mut cipher = ChaCha20::new_with_counter(&key, &nonce, 1);
+    assert_eq!(cipher.current_position(), 64);
+    cipher.apply(&mut block);
+    assert_eq!(cipher.current_position(), 128);
+    assert_eq!(
+        block,
+        decode_fix::<64>(
+            b"10f1e7e4d13b5915500fdd1fa32071c4c7d1f4c733c068030422aa9ac3d46c4e\
+              d2826446079faa0914c2d705d98b02a2b5129cd1de164eb9cbd083e8a2503c4e"
+        )
+        .unwrap()
+    );
+}
+
+// RFC8439 2.4.2
+#[test]
+fn test_chacha20_encrypt() {
+    let key = decode_fix::<32>(b"000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f")
+        .unwrap();
+    let nonce = decode_fix::<12>(b"000000000000004a00000000").unwrap();
+    let plaintext =
+        *b"Ladies and Gentlemen of the class of '99: If I could offer you only one tip \
+        for the future, sunscreen would be it.";
+    let ciphertext = decode_fix::<114>(
+        b"6e2e359a2568f98041ba0728dd0d6981e97e7aec1d4360c20a27afccfd9fae0b\
+          f91b65c5524733ab8f593dabcd62b3571639d624e65152ab8f530c359f0861d8\
+          07ca0dbf500d6a6156a38e088a22b65e52bc514d16ccf806818ce91ab7793736\
+          5af90bbf74a35be6b40b8eedf2785e42874d",
+    )
+    .unwrap();
+
+    let mut data = plaintext;
+    ChaCha20::new_with_counter(&key, &nonce, 1).apply(&mut data);
+    assert_eq!(data, ciphertext);
+
+    // the same keystream from block 0
+    let mut data = plaintext;
+    let mut cipher = ChaCha20::new(&key, &nonce);
+    cipher.seek(64).unwrap();
+    cipher.apply(&mut data);
+    assert_eq!(data, ciphertext);
+
+    // random access to the middle of the message
+    let mut data = plaintext;
+    let mut cipher = ChaCha20::new(&key, &nonce);
+    cipher.seek(64 + 100).unwrap();
+    cipher.apply(&mut data[100..]);
+    assert_eq!(data[100..], ciphertext[100..]);
+}
+
+#[test]
+fn test_chacha20_seek() {
+    let mut key = [0u8; 32];
+    let mut nonce = [0u8; 12];
+    let mut rng = FastRng::new_from_seed(&[0u8; 32]);
+
+    for i in 0..1000u64 {
+        rng.fill_bytes(&mut key);
+        rng.fill_bytes(&mut nonce);
+        let position = i * 37 % 4096;
+
+        let mut d = [0u8; 300];
+
+        let mut std_cipher = chacha20::ChaCha20::new(&key.into(), &nonce.into());
+        std_cipher.seek(position);
+        std_cipher.apply_keystream(&mut d[..77]);
+        std_cipher.apply_keystream(&mut d[77..]);
+
+        let mut cipher = ChaCha20::new(&key, &nonce);
+        cipher.seek(position).unwrap();
+        cipher.apply(&mut d[..77]);
+        assert_eq!(cipher.current_position(), position + 77);
+        cipher.apply(&mut d[77..]);
+        assert_eq!(cipher.current_position(), position + 300);
+
+        assert_eq!(d, [0u8; 300]);
+    }
+}
+
+#[test]
+fn test_chacha20_counter_overflow() {
+    let end = 64 << 32;
+
+    // the last block is usable
+    let mut cipher = ChaCha20::new_with_counter(&[1u8; 32], &[2u8; 12], u32::MAX);
+    let mut d = [0u8; 65];
+    assert_eq!(cipher.try_apply(&mut d), Err(CipherError::CounterOverflow));
+    assert_eq!(d, [0u8; 65]);
+    assert_eq!(cipher.current_position(), end - 64);
+
+    cipher.try_apply(&mut d[..40]).unwrap();
+    cipher.try_apply(&mut d[40..64]).unwrap();
+    assert_eq!(cipher.current_position(), end);
+    assert_eq!(
+        cipher.try_apply(&mut d[64..]),
+        Err(CipherError::CounterOverflow)
+    );
+    cipher.try_apply(&mut []).unwrap();
+
+    let mut cipher = ChaCha20::new(&[1u8; 32], &[2u8; 12]);
+    assert_eq!(cipher.seek(end + 1), Err(CipherError::CounterOverflow));
+    cipher.seek(end - 64).unwrap();
+    let mut e = [0u8; 64];
+    cipher.apply(&mut e);
+    assert_eq!(d[..64], e);
+}
+
+#[test]
+#[should_panic]
+fn test_chacha20_counter_overflow_panic() {
+    let mut cipher = ChaCha20::new_with_counter(&[1u8; 32], &[2u8; 12], u32::MAX);
+    cipher.apply(&mut [0u8; 65]);
+}
+
+macro_rules! fuzz_reduced_rounds {
+    ($name:ident, $cipher:ty, $std_cipher:ty) => {
+        #[test]
+        fn $name() {
+            let mut key = [0u8; 32];
+            let mut nonce = [0u8; 12];
+            let mut rng = FastRng::new_from_seed(&[0u8; 32]);
+
+            for i in 0..1000u64 {
+                rng.fill_bytes(&mut key);
+                rng.fill_bytes(&mut nonce);
+                let position = i * 37 % 4096;
+
+                let mut d = [0u8; 300];
+
+                let mut std_cipher = <$std_cipher>::new(&key.into(), &nonce.into());
+                std_cipher.seek(position);
+                std_cipher.apply_keystream(&mut d[..77]);
+                std_cipher.apply_keystream(&mut d[77..]);
+
+                let mut cipher = <$cipher>::new(&key, &nonce);
+                cipher.seek(position).unwrap();
+                cipher.apply(&mut d[..77]);
+                cipher.apply(&mut d[77..]);
+
+                assert_eq!(d, [0u8; 300]);
+            }
+        }
+    };
+}
+
+fuzz_reduced_rounds!(test_chacha8, ChaCha8, chacha20::ChaCha8);
+fuzz_reduced_rounds!(test_chacha12, ChaCha12, chacha20::ChaCha12);
+
+// draft-strombergson-chacha-test-vectors-01 TC1
+#[test]
+fn test_chacha_legacy() {
+    let mut block = [0u8; 64];
+    ChaCha8Legacy::new(&[0u8; 32], &[0u8; 8]).apply(&mut block);
+    assert_eq!(
+        block,
+        decode_fix::<64>(
+            b"3e00ef2f895f40d67f5bb8e81f09a5a12c840ec3ce9a7f3b181be188ef711a1e\
+              984ce172b9216f419f445367456d5619314a42a3da86b001387bfdb80e0cfe42"
+        )
+        .unwrap()
+    );
+
+    let mut block = [0u8; 64];
+    ChaCha12Legacy::new(&[0u8; 32], &[0u8; 8]).apply(&mut block);
+    assert_eq!(
+        block,
+        decode_fix::<64>(
+            b"9bf49a6a0755f953811fce125f2683d50429c3bb49e074147e0089a52eae155f\
+              0564f879d27ae3c02ce82834acfa8c793a629f2ca0de6919610be82f411326be"
+        )
+        .unwrap()
+    );
+
+    let mut block = [0u8; 64];
+    ChaCha20Legacy::new(&[0u8; 32], &[0u8; 8]).apply(&mut block);
+    assert_eq!(
+        block,
+        decode_fix::<64>(
+            b"76b8e0ada0f13d90405d6ae55386bd28bdd219b8a08ded1aa836efcc8b770dc7\
+              da41597c5157488d7724e03fb8d84a376a43b8f41518a11cc387b669b2ee6586"
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn test_chacha20_legacy_fuzz() {
+    let mut key = [0u8; 32];
+    let mut nonce = [0u8; 8];
+    let mut rng = FastRng::new_from_seed(&[0u8; 32]);
+
+    for _ in 0..1000 {
+        let mut d = [0u8; 1024];
+
+        let mut std_cipher = chacha20::ChaCha20Legacy::new(&key.into(), &nonce.into());
+        std_cipher.apply_keystream(&mut d[..233]);
+        std_cipher.apply_keystream(&mut d[233..]);
+
+        let mut cipher = ChaCha20Legacy::new(&key, &nonce);
+        cipher.apply(&mut d[..233]);
+        cipher.apply(&mut d[233..]);
+
+        assert_eq!(d, [0u8; 1024]);
+
+        rng.fill_bytes(&mut key);
+        rng.fill_bytes(&mut nonce);
+    }
+}
+
+// the keystream of `rand_chacha` is the legacy one, with the stream id as the nonce
+macro_rules! test_rand_chacha {
+    ($name:ident, $cipher:ty, $std_rng:ty) => {
+        #[test]
+        fn $name() {
+            let mut key = [0u8; 32];
+            let mut stream = [0u8; 8];
+            let mut rng = FastRng::new_from_seed(&[0u8; 32]);
+
+            // the last one crosses into the high word of the block counter
+            for block in [0, 1, 1000, u32::MAX as u64] {
+                rng.fill_bytes(&mut key);
+                rng.fill_bytes(&mut stream);
+
+                let mut std_rng = <$std_rng>::from_seed(key);
+                std_rng.set_stream(u64::from_le_bytes(stream));
+                std_rng.set_word_pos(block as u128 * 16 + 3);
+                let mut expected = [0u8; 300];
+                std_rng.fill_bytes(&mut expected);
+
+                let mut d = [0u8; 300];
+                let mut cipher = <$cipher>::new_with_counter(&key, &stream, block);
+                cipher.seek(block as u128 * 64 + 12).unwrap();
+                cipher.apply(&mut d);
+                assert_eq!(d, expected);
+            }
+        }
+    };
+}
+
+test_rand_chacha!(test_rand_chacha8, ChaCha8Legacy, rand_chacha::ChaCha8Rng);
+test_rand_chacha!(test_rand_chacha12, ChaCha12Legacy, rand_chacha::ChaCha12Rng);
+test_rand_chacha!(test_rand_chacha20, ChaCha20Legacy, rand_chacha::ChaCha20Rng);
+
+#[test]
+fn test_chacha_legacy_counter_overflow() {
+    let end = 64u128 << 64;
+
+    let mut cipher = ChaCha20Legacy::new_with_counter(&[1u8; 32], &[2u8; 8], u64::MAX);
+    assert_eq!(cipher.current_position(), end - 64);
+    assert_eq!(
+        cipher.try_apply(&mut [0u8; 65]),
+        Err(CipherError::CounterOverflow)
+    );
+    cipher.try_apply(&mut [0u8; 64]).unwrap();
+    assert_eq!(
+        cipher.try_apply(&mut [0u8; 1]),
+        Err(CipherError::CounterOverflow)
+    );
+    assert_eq!(cipher.seek(end + 1), Err(CipherError::CounterOverflow));
+}
+
+// draft-irtf-cfrg-xchacha-03 2.2.1
+#[test]
+fn test_hchacha20() {
+    let key = decode_fix::<32>(b"000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f")
+        .unwrap();
+    let nonce = decode_fix::<16>(b"000000090000004a0000000031415927").unwrap();
+
+    assert_eq!(
+        hchacha20(&key, &nonce),
+        decode_fix::<32>(b"82413b4227b27bfed30e42508a877d73a0f9e4d58a74a853c12ec41326d3ecdc")
+            .unwrap()
+    );
+}
+
+// draft-irtf-cfrg-xchacha-03 A.3.2
+#[test]
+fn test_xchacha20() {
+    let key = decode_fix::<32>(b"808182838485868788898a8b8c8d8e8f909192939495969798999a9b9c9d9e9f")
+        .unwrap();
+    let nonce = decode_fix::<24>(b"404142434445464748494a4b4c4d4e4f5051525354555658").unwrap();
+    let plaintext = *b"The dhole (pronounced \"dole\") is also known as the Asiatic wild dog, \
+        red dog, and whistling dog. It is about the size of a German shepherd but looks more like \
+        a long-legged fox. This highly elusive and skilled jumper is classified with wolves, \
+        coyotes, jackals, and foxes in the taxonomic family Canidae.";
+
+    let mut data = plaintext;
+    XChaCha20::new(&key, &nonce).apply(&mut data);
+    assert_eq!(
+        data,
+        decode_fix::<304>(
+            b"4559abba4e48c16102e8bb2c05e6947f50a786de162f9b0b7e592a9b53d0d4e9\
+              8d8d6410d540a1a6375b26d80dace4fab52384c731acbf16a5923c0c48d3575d\
+              4d0d2c673b666faa731061277701093a6bf7a158a8864292a41c48e3a9b4c0da\
+              ece0f8d98d0d7e05b37a307bbb66333164ec9e1b24ea0d6c3ffddcec4f68e744\
+              3056193a03c810e11344ca06d8ed8a2bfb1e8d48cfa6bc0eb4e2464b74814240\
+              7c9f431aee769960e15ba8b96890466ef2457599852385c661f752ce20f9da0c\
+              09ab6b19df74e76a95967446f8d0fd415e7bee2a12a114c20eb5292ae7a349ae\
+              577820d5520a1f3fb62a17ce6a7e68fa7c79111d8860920bc048ef43fe84486c\
+              cb87c25f0ae045f0cce1e7989a9aa220a28bdd4827e751a24a6d5c62d790a663\
+              93b93111c1a55dd7421a10184974c7c5"
+        )
+        .unwrap()
+    );
+
+    XChaCha20::new(&key, &nonce).apply(&mut data);
+    assert_eq!(data, plaintext);
+}
+
+#[test]
+fn test_xchacha20_fuzz() {
+    let mut key = [0u8; 32];
+    let mut nonce = [0u8; 24];
+    let mut rng = FastRng::new_from_seed(&[0u8; 32]);
+
+    for _ in 0..1000 {
+        let mut d = [0u8; 1024];
+
+        let mut std_cipher = chacha20::XChaCha20::new(&key.into(), &nonce.into());
+        std_cipher.apply_keystream(&mut d[..233]);
+        std_cipher.apply_keystream(&mut d[233..]);
+
+        let mut cipher = XChaCha20::new(&key, &nonce);
+        cipher.apply(&mut d[..233]);
+        cipher.apply(&mut d[233..]);
+
+        assert_eq!(d, [0u8; 1024]);
+
+        rng.fill_bytes(&mut key);
+        rng.fill_bytes(&mut nonce);
+    }
+}
+
+#[test]
+fn test_xchacha20_counter() {
+    let mut key = [0u8; 32];
+    let mut nonce = [0u8; 24];
+    let mut rng = FastRng::new_from_seed(&[0u8; 32]);
+
+    for i in 0..1000u32 {
+        rng.fill_bytes(&mut key);
+        rng.fill_bytes(&mut nonce);
+        let counter = i * 37;
+
+        let mut d = [0u8; 300];
+
+        let mut std_cipher = chacha20::XChaCha20::new(&key.into(), &nonce.into());
+        std_cipher.seek(counter as u64 * 64);
+        std_cipher.apply_keystream(&mut d);
+
+        let mut cipher = XChaCha20::new_with_counter(&key, &nonce, counter);
+        assert_eq!(cipher.current_position(), counter as u64 * 64);
+        cipher.apply(&mut d);
+
+        assert_eq!(d, [0u8; 300]);
+    }
+}
@@ -1,4 +1,13 @@
-use primit::aead::{chacha20poly1305::Chacha20Poly1305, Aead, Decryptor, Encryptor};
+use chacha20poly1305::{aead::AeadInPlace, KeyInit};
+use primit::{
+    aead::{
+        chacha20poly1305::{Chacha20Poly1305, XChaCha20Poly1305},
+        Aead, Decryptor, Encryptor,
+    },
+    error::AeadError,
+    rng::{FastRng, Rng},
+    utils::hex::decode_fix,
+};
 
 #[test]
 fn test_chacha20poly1305() {
@@ -32,3 +41,89 @@ fn test_chacha20poly1305() {
 
     assert_eq!(data, origin_data);
 }
+
+// draft-irtf-cfrg-xchacha-03 A.3.1
+#[test]
+fn test_xchacha20poly1305() {
+    let key = decode_fix::<32>(b"808182838485868788898a8b8c8d8e8f909192939495969798999a9b9c9d9e9f")
+        .unwrap();
+    let nonce = decode_fix::<24>(b"404142434445464748494a4b4c4d4e4f5051525354555657").unwrap();
+    let aad = decode_fix::<12>(b"50515253c0c1c2c3c4c5c6c7").unwrap();
+    let plaintext =
+        *b"Ladies and Gentlemen of the class of '99: If I could offer you only one tip \
+        for the future, sunscreen would be it.";
+
+    let alg = XChaCha20Poly1305::new(&key);
+    let mut data = plaintext;
+    let mac = alg.encryptor(&nonce, &aad).finalize(&mut data);
+
+    assert_eq!(
+        data,
+        decode_fix::<114>(
+            b"bd6d179d3e83d43b9576579493c0e939572a1700252bfaccbed2902c21396cbb\
+              731c7f1b0b4aa6440bf3a82f4eda7e39ae64c6708c54c216cb96b72e1213b452\
+              2f8c9ba40db5d945b11b69b982c1bb9e3f3fac2bc369488f76b2383565d3fff9\
+              21f9664c97637da9768812f615c68b13b52e"
+        )
+        .unwrap()
+    );
+    assert_eq!(
+        mac,
+        decode_fix::<16>(b"c0875924c1c7987947deafd8780acf49").unwrap()
+    );
+
+    assert!(alg
+        .decryptor(&nonce, &aad)
+        .finalize(&mut data, &mac)
+        .is_ok());
+    assert_eq!(data, plaintext);
+
+    let mut bad_mac = mac;
+    bad_mac[0] ^= 1;
+    assert_eq!(
+        alg.decryptor(&nonce, &aad).finalize(&mut data, &bad_mac),
+        Err(AeadError::BadMac)
+    );
+}
+
+#[test]
+fn test_xchacha20poly1305_fuzz() {
+    let mut rng = FastRng::new_from_seed(&[0u8; 32]);
+    let mut key = [0u8; 32];
+    let mut nonce = [0u8; 24];
+    let mut ad = [0u8; 64];
+    let mut text = [0u8; 300];
+
+    for i in 0..200 {
+        rng.fill_bytes(&mut key);
+        rng.fill_bytes(&mut nonce);
+        rng.fill_bytes(&mut ad);
+        rng.fill_bytes(&mut text);
+        let ad = &ad[..i % 64];
+        let text = &text[..i % 300];
+
+        // whole blocks through `encrypt`, the rest through `finalize`
+        let mut data = text.to_vec();
+        let mut enc = XChaCha20Poly1305::new(&key).encryptor(&nonce, ad);
+        let (blocks, remainder) = data.as_chunks_mut::<64>();
+        for block in blocks {
+            enc.encrypt(block);
+        }
+        let mac = enc.finalize(remainder);
+
+        let mut std_data = text.to_vec();
+        let std_mac = chacha20poly1305::XChaCha20Poly1305::new(&key.into())
+            .encrypt_in_place_detached(&nonce.into(), ad, &mut std_data)
+            .unwrap();
+        assert_eq!(data, std_data);
+        assert_eq!(mac.as_slice(), std_mac.as_slice());
+
+        let mut dec = XChaCha20Poly1305::new(&key).decryptor(&nonce, ad);
+        let (blocks, remainder) = data.as_chunks_mut::<64>();
+        for block in blocks {
+            dec.decrypt(block);
+        }
+        dec.finalize(remainder, &mac).unwrap();
+        assert_eq!(data, text);
+    }
+}
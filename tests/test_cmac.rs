@@ -0,0 +1,96 @@
+use cmac::Mac as _;
+use primit::{
+    mac::{
+        cmac::{cmac, AesCmac, Cmac},
+        Mac,
+    },
+    rng::{FastRng, Rng},
+    symmetry::aes::{Aes128, Aes256},
+    utils::hex::decode_fix,
+};
+
+const MESSAGE: &[u8; 128] = b"6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e5130c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710";
+
+// RFC4493 4
+#[test]
+fn test_aes128_cmac() {
+    let key = decode_fix::<16>(b"2b7e151628aed2a6abf7158809cf4f3c").unwrap();
+    let message = decode_fix::<64>(MESSAGE).unwrap();
+
+    for (length, expected) in [
+        (0, b"bb1d6929e95937287fa37d129b756746"),
+        (16, b"070a16b46b4d4144f79bdd9dd04a287c"),
+        (40, b"dfa66747de9ae63030ca32611497c827"),
+        (64, b"51f0bebf7e3b9d92fc49741779363cfe"),
+    ] {
+        let expected = decode_fix::<16>(expected).unwrap();
+        assert_eq!(AesCmac::new(&key).finalize(&message[..length]), expected);
+        assert_eq!(cmac(Aes128::new(&key), &message[..length]), expected);
+    }
+}
+
+// NIST SP800-38B D.3
+#[test]
+fn test_aes256_cmac() {
+    let key = decode_fix::<32>(b"603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4")
+        .unwrap();
+    let message = decode_fix::<64>(MESSAGE).unwrap();
+
+    for (length, expected) in [
+        (0, b"028962f61b7bf89efc6b551f4667d983"),
+        (16, b"28a7023f452e8f82bd4bf28d8c37c35c"),
+        (40, b"aaf3d8f1de5640c232f5b169b9c911e6"),
+        (64, b"e1992190549f6ed5696a2c056c315410"),
+    ] {
+        let expected = decode_fix::<16>(expected).unwrap();
+        assert_eq!(
+            Cmac::<Aes256>::new(&key).finalize(&message[..length]),
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_cmac_incremental() {
+    let key = decode_fix::<16>(b"2b7e151628aed2a6abf7158809cf4f3c").unwrap();
+    let message = decode_fix::<64>(MESSAGE).unwrap();
+
+    for length in 0..=64 {
+        let expected = cmac(Aes128::new(&key), &message[..length]);
+
+        // full blocks through `update`, the rest to `finalize`
+        let mut mac = AesCmac::new(&key);
+        let (blocks, remainder) = message[..length].as_chunks::<16>();
+        for block in blocks {
+            mac.update(block);
+        }
+        assert_eq!(mac.finalize(remainder), expected);
+
+        for split in 0..=length {
+            let mut mac = Cmac::from_cipher(Aes128::new(&key));
+            mac.update_slice(&message[..split]);
+            mac.update_slice(&message[split..length]);
+            assert_eq!(mac.digest(), expected);
+        }
+    }
+}
+
+#[test]
+fn test_cmac_fuzz() {
+    let mut rng = FastRng::new_from_seed(&[0u8; 32]);
+    let mut key = [0u8; 16];
+    let mut message = [0u8; 256];
+
+    for length in 0..256 {
+        rng.fill_bytes(&mut key);
+        rng.fill_bytes(&mut message);
+
+        let mut std_mac = cmac::Cmac::<aes::Aes128>::new_from_slice(&key).unwrap();
+        std_mac.update(&message[..length]);
+
+        assert_eq!(
+            cmac(Aes128::new(&key), &message[..length]),
+            std_mac.finalize().into_bytes().as_slice()
+        );
+    }
+}
@@ -0,0 +1,95 @@
+use aes::cipher::{KeyIvInit, StreamCipher};
+use primit::{
+    rng::{FastRng, Rng},
+    symmetry::{
+        aes::{Aes128, Aes256},
+        mode::ctr::{CounterEndian, Ctr},
+    },
+    utils::hex::decode_fix,
+};
+
+// NIST SP800-38A F.5
+const PLAINTEXT: &[u8; 128] = b"6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e5130c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710";
+const IV: &[u8; 32] = b"f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff";
+
+#[test]
+fn test_ctr_aes128() {
+    let cipher = Aes128::new(&decode_fix::<16>(b"2b7e151628aed2a6abf7158809cf4f3c").unwrap());
+    let mut data = decode_fix::<64>(PLAINTEXT).unwrap();
+
+    Ctr::new(cipher, &decode_fix::<16>(IV).unwrap()).apply(&mut data);
+    assert_eq!(data, decode_fix::<64>(b"874d6191b620e3261bef6864990db6ce9806f66b7970fdff8617187bb9fffdff5ae4df3edbd5d35e5b4f09020db03eab1e031dda2fbe03d1792170a0f3009cee").unwrap());
+
+    Ctr::new(cipher, &decode_fix::<16>(IV).unwrap()).apply(&mut data);
+    assert_eq!(data, decode_fix::<64>(PLAINTEXT).unwrap());
+}
+
+#[test]
+fn test_ctr_aes256() {
+    let cipher = Aes256::new(
+        &decode_fix::<32>(b"603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4")
+            .unwrap(),
+    );
+    let mut data = decode_fix::<64>(PLAINTEXT).unwrap();
+
+    Ctr::new(cipher, &decode_fix::<16>(IV).unwrap()).apply(&mut data);
+    assert_eq!(data, decode_fix::<64>(b"601ec313775789a5b7a7f504bbf3d228f443e3ca4d62b59aca84e990cacaf5c52b0930daa23de94ce87017ba2d84988ddfc9c58db67aada613c2dd08457941a6").unwrap());
+}
+
+#[test]
+fn test_ctr_counter() {
+    fn tester<S: StreamCipher + KeyIvInit>(size: usize, endian: CounterEndian) {
+        let mut rng = FastRng::new_from_seed(&[0u8; 32]);
+        let mut key = [0u8; 16];
+        rng.fill_bytes(&mut key);
+
+        // counters next to overflow
+        for iv in [[0u8; 16], [0xffu8; 16], [0xfe; 16]] {
+            let mut data = [0u8; 1024];
+            let mut std_cipher = S::new_from_slices(&key, &iv).unwrap();
+            std_cipher.apply_keystream(&mut data);
+
+            let mut cipher = Ctr::new_with_counter(Aes128::new(&key), &iv, size, endian);
+            cipher.apply(&mut data[..233]);
+            cipher.apply(&mut data[233..240]);
+            cipher.apply(&mut data[240..]);
+
+            assert_eq!(data, [0u8; 1024]);
+        }
+    }
+
+    tester::<ctr::Ctr128BE<aes::Aes128>>(16, CounterEndian::Big);
+    tester::<ctr::Ctr64BE<aes::Aes128>>(8, CounterEndian::Big);
+    tester::<ctr::Ctr32BE<aes::Aes128>>(4, CounterEndian::Big);
+    tester::<ctr::Ctr64LE<aes::Aes128>>(8, CounterEndian::Little);
+    tester::<ctr::Ctr32LE<aes::Aes128>>(4, CounterEndian::Little);
+}
+
+#[test]
+fn test_ctr_seek() {
+    let mut key = [0u8; 16];
+    let mut iv = [0u8; 16];
+    let mut rng = FastRng::new_from_seed(&[0u8; 32]);
+    rng.fill_bytes(&mut key);
+    rng.fill_bytes(&mut iv);
+
+    let mut keystream = [0u8; 1024];
+    Ctr::new(Aes128::new(&key), &iv).apply(&mut keystream);
+
+    let mut cipher = Ctr::new(Aes128::new(&key), &iv);
+    for (start, end) in [
+        (100, 300),
+        (0, 17),
+        (512, 1024),
+        (15, 16),
+        (16, 64),
+        (1000, 1024),
+    ] {
+        let mut data = [0u8; 1024];
+        cipher.seek(start as u64);
+        assert_eq!(cipher.current_position(), start as u64);
+        cipher.apply(&mut data[start..end]);
+        assert_eq!(cipher.current_position(), end as u64);
+        assert_eq!(data[start..end], keystream[start..end]);
+    }
+}
@@ -0,0 +1,53 @@
+use primit::{
+    error::CipherError,
+    symmetry::{
+        aes::{Aes128, Aes256},
+        mode::ecb,
+    },
+    utils::hex::decode_fix,
+};
+
+// NIST SP800-38A F.1
+const PLAINTEXT: &[u8; 128] = b"6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e5130c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710";
+
+#[test]
+fn test_ecb_aes128() {
+    let cipher = Aes128::new(&decode_fix::<16>(b"2b7e151628aed2a6abf7158809cf4f3c").unwrap());
+    let mut data = decode_fix::<64>(PLAINTEXT).unwrap();
+
+    ecb::encrypt(&cipher, &mut data).unwrap();
+    assert_eq!(data, decode_fix::<64>(b"3ad77bb40d7a3660a89ecaf32466ef97f5d3d58503b9699de785895a96fdbaaf43b1cd7f598ece23881b00e3ed0306887b0c785e27e8ad3f8223207104725dd4").unwrap());
+
+    ecb::decrypt(&cipher, &mut data).unwrap();
+    assert_eq!(data, decode_fix::<64>(PLAINTEXT).unwrap());
+}
+
+#[test]
+fn test_ecb_aes256() {
+    let cipher = Aes256::new(
+        &decode_fix::<32>(b"603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4")
+            .unwrap(),
+    );
+    let mut data = decode_fix::<64>(PLAINTEXT).unwrap();
+
+    ecb::encrypt(&cipher, &mut data).unwrap();
+    assert_eq!(data, decode_fix::<64>(b"f3eed1bdb5d2a03c064b5a7e3db181f8591ccb10d410ed26dc5ba74a31362870b6ed21b99ca6f4f9f153e7b1beafed1d23304b7a39f9f3ff067d8d8f9e24ecc7").unwrap());
+
+    ecb::decrypt(&cipher, &mut data).unwrap();
+    assert_eq!(data, decode_fix::<64>(PLAINTEXT).unwrap());
+}
+
+#[test]
+fn test_ecb_invalid_length() {
+    let cipher = Aes128::new(&[0u8; 16]);
+    let mut data = [0u8; 33];
+
+    assert_eq!(
+        ecb::encrypt(&cipher, &mut data),
+        Err(CipherError::InvalidLength)
+    );
+    assert_eq!(
+        ecb::decrypt(&cipher, &mut data),
+        Err(CipherError::InvalidLength)
+    );
+}
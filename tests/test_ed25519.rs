@@ -0,0 +1,97 @@
+use primit::{
+    ec::{ed25519::Ed25519, DSA},
+    hash::sha512::sha512,
+    rng::{FastRng, Rng},
+    utils::hex::decode_fix,
+};
+
+fn check(sk: &[u8; 64], pk: &[u8; 64], msg: &[u8], sig: &[u8; 128]) {
+    let sk = Ed25519::new(&decode_fix::<32>(sk).unwrap());
+    let pk = decode_fix::<32>(pk).unwrap();
+    let sig = decode_fix::<64>(sig).unwrap();
+
+    assert_eq!(sk.to_public(), pk);
+    assert_eq!(sk.sign(msg), sig);
+    assert!(Ed25519::verify(&pk, msg, &sig).is_ok());
+}
+
+#[test]
+fn test_ed25519() {
+    // RFC8032 7.1 TEST 1
+    check(
+        b"9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+        b"d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+        b"",
+        b"e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b",
+    );
+
+    // RFC8032 7.1 TEST 2
+    check(
+        b"4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb",
+        b"3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+        &[0x72],
+        b"92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00",
+    );
+
+    // RFC8032 7.1 TEST 3
+    check(
+        b"c5aa8df43f9f837bedb7442f31dcb7b166d38535076f094b85ce3a2e0b4458f7",
+        b"fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+        &[0xaf, 0x82],
+        b"6291d657deec24024827e69c3abe01a30ce548a284743a445e3680d7db5ac3ac18ff9b538d16f290ae67f760984dc6594a7c15e9716ed28dc027beceea1ec40a",
+    );
+
+    // RFC8032 7.1 TEST SHA(abc)
+    check(
+        b"833fe62409237b9d62ec77587520911e9a759cec1d19755b7da901b96dca3d42",
+        b"ec172b93ad5e563bf4932c70e1245034c35467ef2efd4d64ebf819683467e2bf",
+        &sha512(b"abc"),
+        b"dc2a4459e7369633a52b1bf277839a00201009a3efbf3ecb69bea2186c26b58909351fc9ac90b3ecfdfbc7c66431e0303dca179c138ac17ad9bef1177331a704",
+    );
+
+    // message longer than one SHA512 block
+    let msg: [u8; 200] = core::array::from_fn(|i| i as u8);
+    check(
+        b"0000000000000000000000000000000000000000000000000000000000000000",
+        b"3b6a27bcceb6a42d62a3a8d02a6f0d73653215771de243a63ac048a18b59da29",
+        &msg,
+        b"234dfd88b8496f2c5d422959d5827a19b90df2e179c365069ee8f40558638d5aac830cdd796ee360b049887a0fb72fce26e42fa5f180d4591aae273f4f466609",
+    );
+}
+
+#[test]
+fn test_ed25519_invalid() {
+    let mut rng = FastRng::new_from_seed(&[0u8; 32]);
+    let mut msg = [0u8; 100];
+    for i in 0..20 {
+        rng.fill_bytes(&mut msg);
+        let sk = Ed25519::generate(&mut rng);
+        let pk = sk.to_public();
+        let sig = sk.sign(&msg[..i * 5]);
+        assert!(Ed25519::verify(&pk, &msg[..i * 5], &sig).is_ok());
+
+        // tampered message
+        assert!(Ed25519::verify(&pk, &msg[..i * 5 + 1], &sig).is_err());
+
+        // tampered R and S
+        let mut bad = sig;
+        bad[i] ^= 1;
+        assert!(Ed25519::verify(&pk, &msg[..i * 5], &bad).is_err());
+        let mut bad = sig;
+        bad[32 + i] ^= 1;
+        assert!(Ed25519::verify(&pk, &msg[..i * 5], &bad).is_err());
+    }
+
+    // S >= L
+    let sk = Ed25519::new(&[0u8; 32]);
+    let pk = sk.to_public();
+    let mut sig = sk.sign(b"");
+    sig[32..].copy_from_slice(
+        &decode_fix::<32>(b"edd3f55c1a631258d69cf7a2def9de1400000000000000000000000000000010")
+            .unwrap(),
+    );
+    assert!(Ed25519::verify(&pk, b"", &sig).is_err());
+
+    // y >= P25519
+    assert!(Ed25519::verify(&[0xff; 32], b"", &sig).is_err());
+}
@@ -0,0 +1,51 @@
+use primit::{
+    hash::{
+        md5::{md5, MD5},
+        sha256::{sha256, SHA256},
+        sha512::{sha512, SHA512},
+        Hasher,
+    },
+    rng::{FastRng, Rng},
+};
+
+#[test]
+fn test_hasher_fuzz() {
+    let mut input = [0u8; 1024];
+    let mut rng = FastRng::new_from_seed(&[0u8; 32]);
+    rng.fill_bytes(&mut input);
+
+    for l in 0..300 {
+        let mut h256 = Hasher::<SHA256>::new();
+        let mut h512 = Hasher::<SHA512>::new();
+        let mut hmd5 = Hasher::<MD5>::new();
+
+        // split input into random sized pieces
+        let mut offset = 0;
+        while offset < l {
+            let mut n = [0u8; 1];
+            rng.fill_bytes(&mut n);
+            let end = (offset + n[0] as usize % 150).min(l);
+            h256.update(&input[offset..end]);
+            h512.update(&input[offset..end]);
+            hmd5.update(&input[offset..end]);
+            offset = end;
+        }
+
+        assert_eq!(h256.finalize(), sha256(&input[..l]));
+        assert_eq!(h512.finalize(), sha512(&input[..l]));
+        assert_eq!(hmd5.finalize(), md5(&input[..l]));
+    }
+}
+
+#[test]
+fn test_hasher_clone() {
+    let mut h = Hasher::<SHA256>::new();
+    h.update(b"hello ");
+
+    let mut h2 = h.clone();
+    h.update(b"world");
+    h2.update(b"primit");
+
+    assert_eq!(h.finalize(), sha256(b"hello world"));
+    assert_eq!(h2.finalize(), sha256(b"hello primit"));
+}
@@ -0,0 +1,128 @@
+use primit::{
+    error::KdfError,
+    hash::{sha1::SHA1, sha256::SHA256},
+    kdf::hkdf::{expand, expand_label, extract},
+    utils::hex::decode_fix,
+};
+
+#[test]
+fn test_hkdf_sha256() {
+    // RFC5869 A.1
+    let ikm = [0x0b; 22];
+    let salt = decode_fix::<13>(b"000102030405060708090a0b0c").unwrap();
+    let info = decode_fix::<10>(b"f0f1f2f3f4f5f6f7f8f9").unwrap();
+
+    let prk = extract::<SHA256>(&salt, &ikm);
+    assert_eq!(
+        prk,
+        decode_fix::<32>(b"077709362c2e32df0ddc3f0dc47bba6390b6c73bb50f9c3122ec844ad7c2b3e5")
+            .unwrap()
+    );
+    let mut okm = [0u8; 42];
+    expand::<SHA256>(&prk, &info, &mut okm).unwrap();
+    assert_eq!(
+        okm,
+        decode_fix::<42>(
+            b"3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865"
+        )
+        .unwrap()
+    );
+
+    // RFC5869 A.2
+    let mut ikm = [0u8; 80];
+    let mut salt = [0u8; 80];
+    let mut info = [0u8; 80];
+    for i in 0..80 {
+        ikm[i] = i as u8;
+        salt[i] = 0x60 + i as u8;
+        info[i] = 0xb0 + i as u8;
+    }
+
+    let prk = extract::<SHA256>(&salt, &ikm);
+    assert_eq!(
+        prk,
+        decode_fix::<32>(b"06a6b88c5853361a06104c9ceb35b45cef760014904671014a193f40c15fc244")
+            .unwrap()
+    );
+    let mut okm = [0u8; 82];
+    expand::<SHA256>(&prk, &info, &mut okm).unwrap();
+    assert_eq!(okm, decode_fix::<82>(b"b11e398dc80327a1c8e7f78c596a49344f012eda2d4efad8a050cc4c19afa97c59045a99cac7827271cb41c65e590e09da3275600c2f09b8367793a9aca3db71cc30c58179ec3e87c14c01d5c1f3434f1d87").unwrap());
+
+    // RFC5869 A.3
+    let prk = extract::<SHA256>(&[], &[0x0b; 22]);
+    assert_eq!(
+        prk,
+        decode_fix::<32>(b"19ef24a32c717b167f33a91d6f648bdf96596776afdb6377ac434c1c293ccb04")
+            .unwrap()
+    );
+    let mut okm = [0u8; 42];
+    expand::<SHA256>(&prk, &[], &mut okm).unwrap();
+    assert_eq!(
+        okm,
+        decode_fix::<42>(
+            b"8da4e775a563c18f715f802a063c5a31b8a11f5c5ee1879ec3454e5f3c738d2d9d201395faa4b61a96c8"
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn test_hkdf_sha1() {
+    // RFC5869 A.4
+    let ikm = [0x0b; 11];
+    let salt = decode_fix::<13>(b"000102030405060708090a0b0c").unwrap();
+    let info = decode_fix::<10>(b"f0f1f2f3f4f5f6f7f8f9").unwrap();
+
+    let prk = extract::<SHA1>(&salt, &ikm);
+    assert_eq!(
+        prk,
+        decode_fix::<20>(b"9b6c18c432a7bf8f0e71c8eb88f4b30baa2ba243").unwrap()
+    );
+    let mut okm = [0u8; 42];
+    expand::<SHA1>(&prk, &info, &mut okm).unwrap();
+    assert_eq!(
+        okm,
+        decode_fix::<42>(
+            b"085a01ea1b10f36933068b56efa5ad81a4f14b822f5b091568a9cdd4f155fda2c22e422478d305f3f896"
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn test_hkdf_expand_label() {
+    // RFC8448 3, early secret and Derive-Secret(., "derived", "")
+    let early_secret = extract::<SHA256>(&[], &[0u8; 32]);
+    assert_eq!(
+        early_secret,
+        decode_fix::<32>(b"33ad0a1c607ec03b09e6cd9893680ce210adf300aa1f2660e1b22e10f170f92a")
+            .unwrap()
+    );
+
+    // SHA256("")
+    let context =
+        decode_fix::<32>(b"e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855")
+            .unwrap();
+    let mut derived = [0u8; 32];
+    expand_label::<SHA256>(&early_secret, b"derived", &context, &mut derived).unwrap();
+    assert_eq!(
+        derived,
+        decode_fix::<32>(b"6f2615a108c702c5678f54fc9dbab69716c076189c48250cebeac3576c3611ba")
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_hkdf_invalid_length() {
+    let mut okm = [0u8; 255 * 32 + 1];
+    assert_eq!(
+        expand::<SHA256>(&[0u8; 32], &[], &mut okm),
+        Err(KdfError::InvalidLength)
+    );
+    assert!(expand::<SHA256>(&[0u8; 32], &[], &mut okm[..255 * 32]).is_ok());
+
+    assert_eq!(
+        expand_label::<SHA256>(&[0u8; 32], &[b'a'; 250], &[], &mut okm[..32]),
+        Err(KdfError::InvalidLength)
+    );
+}
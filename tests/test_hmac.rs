@@ -0,0 +1,108 @@
+use primit::{
+    hash::{md5::MD5, sha1::SHA1, sha256::SHA256, sha384::SHA384, sha512::SHA512},
+    mac::{
+        hmac::{hmac, Hmac},
+        Mac,
+    },
+    rng::{FastRng, Rng},
+    utils::hex::decode_fix,
+};
+
+#[test]
+fn test_hmac_sha2() {
+    // RFC4231 4.2
+    let key = [0x0b; 20];
+    let msg = b"Hi There";
+    assert_eq!(
+        hmac::<SHA256>(&key, msg),
+        decode_fix::<32>(b"b0344c61d8db38535ca8afceaf0bf12b881dc200c9833da726e9376c2e32cff7")
+            .unwrap()
+    );
+    assert_eq!(
+        hmac::<SHA384>(&key, msg),
+        decode_fix::<48>(b"afd03944d84895626b0825f4ab46907f15f9dadbe4101ec682aa034c7cebc59cfaea9ea9076ede7f4af152e8b2fa9cb6").unwrap()
+    );
+    assert_eq!(
+        hmac::<SHA512>(&key, msg),
+        decode_fix::<64>(b"87aa7cdea5ef619d4ff0b4241a1d6cb02379f4e2ce4ec2787ad0b30545e17cdedaa833b7d6b8a702038b274eaea3f4e4be9d914eeb61f1702e696c203a126854").unwrap()
+    );
+
+    // RFC4231 4.3
+    let key = b"Jefe";
+    let msg = b"what do ya want for nothing?";
+    assert_eq!(
+        hmac::<SHA256>(key, msg),
+        decode_fix::<32>(b"5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843")
+            .unwrap()
+    );
+    assert_eq!(
+        hmac::<SHA384>(key, msg),
+        decode_fix::<48>(b"af45d2e376484031617f78d2b58a6b1b9c7ef464f5a01b47e42ec3736322445e8e2240ca5e69e2c78b3239ecfab21649").unwrap()
+    );
+    assert_eq!(
+        hmac::<SHA512>(key, msg),
+        decode_fix::<64>(b"164b7a7bfcf819e2e395fbe73b56e0a387bd64222e831fd610270cd7ea2505549758bf75c05a994a6d034f65f8f0e6fdcaeab1a34d4a6b4b636e070a38bce737").unwrap()
+    );
+
+    // RFC4231 4.7, key larger than block size
+    let key = [0xaa; 131];
+    let msg = b"Test Using Larger Than Block-Size Key - Hash Key First";
+    assert_eq!(
+        hmac::<SHA256>(&key, msg),
+        decode_fix::<32>(b"60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54")
+            .unwrap()
+    );
+    assert_eq!(
+        hmac::<SHA384>(&key, msg),
+        decode_fix::<48>(b"4ece084485813e9088d2c63a041bc5b44f9ef1012a2b588f3cd11f05033ac4c60c2ef6ab4030fe8296248df163f44952").unwrap()
+    );
+    assert_eq!(
+        hmac::<SHA512>(&key, msg),
+        decode_fix::<64>(b"80b24263c7c1a3ebb71493c1dd7be8b49b46d1f41b4aeec1121b013783f8f3526b56d037e05f2598bd0fd2215d6a1e5295e64f73f63f0aec8b915a985d786598").unwrap()
+    );
+}
+
+#[test]
+fn test_hmac_legacy() {
+    // RFC2202 test case 1 and 6
+    let key = [0x0b; 20];
+    let msg = b"Hi There";
+    assert_eq!(
+        hmac::<SHA1>(&key, msg),
+        decode_fix::<20>(b"b617318655057264e28bc0b6fb378c8ef146be00").unwrap()
+    );
+    assert_eq!(
+        hmac::<MD5>(&key, msg),
+        decode_fix::<16>(b"5ccec34ea9656392457fa1ac27f08fbc").unwrap()
+    );
+
+    let key = [0xaa; 80];
+    let msg = b"Test Using Larger Than Block-Size Key - Hash Key First";
+    assert_eq!(
+        hmac::<SHA1>(&key, msg),
+        decode_fix::<20>(b"aa4ae5e15272d00e95705637ce8a3b55ed402112").unwrap()
+    );
+    assert_eq!(
+        hmac::<MD5>(&key, msg),
+        decode_fix::<16>(b"6b1ab7fe4bd7bf8f0b62e6ce61b9d0cd").unwrap()
+    );
+}
+
+#[test]
+fn test_hmac_incremental() {
+    let mut key = [0u8; 32];
+    let mut msg = [0u8; 300];
+    let mut rng = FastRng::new_from_seed(&[0u8; 32]);
+    rng.fill_bytes(&mut key);
+    rng.fill_bytes(&mut msg);
+
+    let keyed = Hmac::<SHA256>::new(&key);
+    for l in 0..msg.len() {
+        let mut h = keyed.clone();
+        let (blocks, remainder) = msg[..l].as_chunks::<64>();
+        for block in blocks {
+            h.update(block);
+        }
+        assert_eq!(h.finalize(remainder), hmac::<SHA256>(&key, &msg[..l]));
+    }
+}
@@ -0,0 +1,205 @@
+use aes_kw::KekAes128;
+use primit::{
+    error::CipherError,
+    rng::{FastRng, Rng},
+    symmetry::{
+        aes::{Aes128, Aes192, Aes256},
+        mode::kw::{self, padded_wrapped_length},
+    },
+    utils::hex::decode_fix,
+};
+
+// RFC3394 4.1
+#[test]
+fn test_kw_aes128() {
+    let cipher = Aes128::new(&decode_fix::<16>(b"000102030405060708090a0b0c0d0e0f").unwrap());
+    let key = decode_fix::<16>(b"00112233445566778899aabbccddeeff").unwrap();
+
+    let mut wrapped = [0u8; 24];
+    kw::wrap(&cipher, &key, &mut wrapped).unwrap();
+    assert_eq!(
+        wrapped,
+        decode_fix::<24>(b"1fa68b0a8112b447aef34bd8fb5a7b829d3e862371d2cfe5").unwrap()
+    );
+
+    let mut unwrapped = [0u8; 16];
+    kw::unwrap(&cipher, &wrapped, &mut unwrapped).unwrap();
+    assert_eq!(unwrapped, key);
+}
+
+// RFC3394 4.6
+#[test]
+fn test_kw_aes256() {
+    let cipher = Aes256::new(
+        &decode_fix::<32>(b"000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f")
+            .unwrap(),
+    );
+    let key = decode_fix::<32>(b"00112233445566778899aabbccddeeff000102030405060708090a0b0c0d0e0f")
+        .unwrap();
+
+    let mut wrapped = [0u8; 40];
+    kw::wrap(&cipher, &key, &mut wrapped).unwrap();
+    assert_eq!(
+        wrapped,
+        decode_fix::<40>(
+            b"28c9f404c4b810f4cbccb35cfb87f8263f5786e2d80ed326cbc7f0e71a99f43bfb988b9b7a02dd21"
+        )
+        .unwrap()
+    );
+
+    let mut unwrapped = [0u8; 32];
+    kw::unwrap(&cipher, &wrapped, &mut unwrapped).unwrap();
+    assert_eq!(unwrapped, key);
+}
+
+// RFC5649 6
+#[test]
+fn test_kw_padded() {
+    let cipher = Aes192::new(
+        &decode_fix::<24>(b"5840df6e29b02af1ab493b705bf16ea1ae8338f4dcc176a8").unwrap(),
+    );
+
+    let key = decode_fix::<20>(b"c37b7e6492584340bed12207808941155068f738").unwrap();
+    let mut wrapped = [0u8; 32];
+    assert_eq!(padded_wrapped_length(key.len()), 32);
+    kw::wrap_padded(&cipher, &key, &mut wrapped).unwrap();
+    assert_eq!(
+        wrapped,
+        decode_fix::<32>(b"138bdeaa9b8fa7fc61f97742e72248ee5ae6ae5360d1ae6a5f54f373fa543b6a")
+            .unwrap()
+    );
+    let mut unwrapped = [0u8; 24];
+    assert_eq!(kw::unwrap_padded(&cipher, &wrapped, &mut unwrapped), Ok(20));
+    assert_eq!(unwrapped[..20], key);
+
+    let key = decode_fix::<7>(b"466f7250617369").unwrap();
+    let mut wrapped = [0u8; 16];
+    assert_eq!(padded_wrapped_length(key.len()), 16);
+    kw::wrap_padded(&cipher, &key, &mut wrapped).unwrap();
+    assert_eq!(
+        wrapped,
+        decode_fix::<16>(b"afbeb0f07dfbf5419200f2ccb50bb24f").unwrap()
+    );
+    let mut unwrapped = [0u8; 8];
+    assert_eq!(kw::unwrap_padded(&cipher, &wrapped, &mut unwrapped), Ok(7));
+    assert_eq!(unwrapped[..7], key);
+}
+
+#[test]
+fn test_kw_integrity() {
+    let cipher = Aes128::new(&[0u8; 16]);
+
+    let mut wrapped = [0u8; 24];
+    kw::wrap(&cipher, &[1u8; 16], &mut wrapped).unwrap();
+    for i in 0..wrapped.len() {
+        let mut tampered = wrapped;
+        tampered[i] ^= 1;
+        let mut unwrapped = [0u8; 16];
+        assert_eq!(
+            kw::unwrap(&cipher, &tampered, &mut unwrapped),
+            Err(CipherError::IntegrityCheckFailed)
+        );
+        assert_eq!(unwrapped, [0u8; 16]);
+    }
+
+    for length in [5, 8, 20] {
+        let mut wrapped = [0u8; 32];
+        let wrapped = &mut wrapped[..padded_wrapped_length(length)];
+        kw::wrap_padded(&cipher, &[1u8; 20][..length], wrapped).unwrap();
+        for i in 0..wrapped.len() {
+            let mut tampered = [0u8; 32];
+            let tampered = &mut tampered[..wrapped.len()];
+            tampered.copy_from_slice(wrapped);
+            tampered[i] ^= 1;
+            assert_eq!(
+                kw::unwrap_padded(&cipher, tampered, &mut [0u8; 24]),
+                Err(CipherError::IntegrityCheckFailed)
+            );
+        }
+    }
+
+    // a padded wrap is not a valid RFC3394 wrap and vice versa
+    let mut wrapped = [0u8; 24];
+    kw::wrap_padded(&cipher, &[1u8; 16], &mut wrapped).unwrap();
+    assert_eq!(
+        kw::unwrap(&cipher, &wrapped, &mut [0u8; 16]),
+        Err(CipherError::IntegrityCheckFailed)
+    );
+    kw::wrap(&cipher, &[1u8; 16], &mut wrapped).unwrap();
+    assert_eq!(
+        kw::unwrap_padded(&cipher, &wrapped, &mut [0u8; 16]),
+        Err(CipherError::IntegrityCheckFailed)
+    );
+}
+
+#[test]
+fn test_kw_invalid_length() {
+    let cipher = Aes128::new(&[0u8; 16]);
+
+    assert_eq!(
+        kw::wrap(&cipher, &[0u8; 8], &mut [0u8; 16]),
+        Err(CipherError::InvalidLength)
+    );
+    assert_eq!(
+        kw::wrap(&cipher, &[0u8; 20], &mut [0u8; 28]),
+        Err(CipherError::InvalidLength)
+    );
+    assert_eq!(
+        kw::wrap(&cipher, &[0u8; 16], &mut [0u8; 16]),
+        Err(CipherError::InvalidLength)
+    );
+    assert_eq!(
+        kw::unwrap(&cipher, &[0u8; 16], &mut [0u8; 8]),
+        Err(CipherError::InvalidLength)
+    );
+    assert_eq!(
+        kw::wrap_padded(&cipher, &[], &mut [0u8; 8]),
+        Err(CipherError::InvalidLength)
+    );
+    assert_eq!(
+        kw::unwrap_padded(&cipher, &[0u8; 20], &mut [0u8; 16]),
+        Err(CipherError::InvalidLength)
+    );
+}
+
+#[test]
+fn test_kw_fuzz() {
+    let mut rng = FastRng::new_from_seed(&[0u8; 32]);
+    let mut kek = [0u8; 16];
+    let mut key = [0u8; 64];
+
+    for length in 1..=64 {
+        rng.fill_bytes(&mut kek);
+        rng.fill_bytes(&mut key);
+        let key = &key[..length];
+
+        let cipher = Aes128::new(&kek);
+        let std_cipher = KekAes128::new(&kek.into());
+
+        let mut wrapped = [0u8; 72];
+        let mut std_wrapped = [0u8; 72];
+        let wrapped = &mut wrapped[..padded_wrapped_length(length)];
+        let std_wrapped = &mut std_wrapped[..padded_wrapped_length(length)];
+        kw::wrap_padded(&cipher, key, wrapped).unwrap();
+        std_cipher.wrap_with_padding(key, std_wrapped).unwrap();
+        assert_eq!(wrapped, std_wrapped);
+
+        let mut unwrapped = [0u8; 64];
+        assert_eq!(
+            kw::unwrap_padded(&cipher, wrapped, &mut unwrapped),
+            Ok(length)
+        );
+        assert_eq!(&unwrapped[..length], key);
+
+        if length >= 16 && length % 8 == 0 {
+            let wrapped = &mut wrapped[..length + 8];
+            let std_wrapped = &mut std_wrapped[..length + 8];
+            kw::wrap(&cipher, key, wrapped).unwrap();
+            std_cipher.wrap(key, std_wrapped).unwrap();
+            assert_eq!(wrapped, std_wrapped);
+
+            kw::unwrap(&cipher, wrapped, &mut unwrapped[..length]).unwrap();
+            assert_eq!(&unwrapped[..length], key);
+        }
+    }
+}
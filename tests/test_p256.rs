@@ -1,12 +1,16 @@
 use aes::cipher::generic_array::GenericArray;
 use p256::{
-    elliptic_curve::{point::AffineCoordinates, PrimeField},
-    AffinePoint, Scalar,
+    ecdsa::{
+        signature::{Signer, Verifier},
+        Signature, SigningKey, VerifyingKey,
+    },
+    elliptic_curve::{ops::Reduce, point::AffineCoordinates, PrimeField},
+    AffinePoint, Scalar, U256,
 };
 use primit::{
     ec::{
-        p256::{G, P256},
-        ECDH,
+        p256::{compress, decompress, Scalar256, G, P256},
+        DSA, ECDH,
     },
     rng::{FastRng, Rng},
     utils::hex::decode_fix,
@@ -41,3 +45,141 @@ fn test_std_p256() {
         assert_eq!(std_result.as_slice(), result);
     }
 }
+
+#[test]
+fn test_p256_ecdsa() {
+    // RFC6979 A.2.5
+    let sk = decode_fix::<32>(b"c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721")
+        .unwrap();
+    let pk = decode_fix::<65>(b"0460fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb67903fe1008b8bc99a41ae9e95628bc64f2f1b20c2d7e9f5177a3c294d4462299").unwrap();
+
+    let sk = P256::new(&sk);
+    assert_eq!(sk.to_public(), pk);
+
+    let sig = decode_fix::<64>(b"efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716f7cb1c942d657c41d436c7a1b6e29f65f3e900dbb9aff4064dc4ab2f843acda8").unwrap();
+    assert_eq!(sk.sign(b"sample"), sig);
+    assert!(P256::verify(&pk, b"sample", &sig).is_ok());
+
+    let sig = decode_fix::<64>(b"f1abb023518351cd71d881567b1ea663ed3efcf6c5132b354f28d3b0b7d38367019f4113742a2b14bd25926b49c649155f267e60d3814b4c0cc84250e46f0083").unwrap();
+    assert_eq!(sk.sign(b"test"), sig);
+    assert!(P256::verify(&pk, b"test", &sig).is_ok());
+
+    assert!(P256::verify(&pk, b"sample", &sig).is_err());
+    assert!(P256::verify(&pk, b"test", &[0u8; 64]).is_err());
+}
+
+#[test]
+fn test_std_p256_ecdsa() {
+    let mut x = [0u8; 32];
+    let mut msg = [0u8; 64];
+    let mut rng = FastRng::new_from_seed(&[0u8; 32]);
+    for _ in 0..100 {
+        rng.fill_bytes(&mut x);
+        rng.fill_bytes(&mut msg);
+
+        let std_sk = SigningKey::from_bytes(GenericArray::from_slice(&x)).unwrap();
+        let std_pk = VerifyingKey::from(&std_sk);
+
+        let sk = P256::new(&x);
+        let pk = sk.to_public();
+        assert_eq!(std_pk.to_encoded_point(false).as_bytes(), pk);
+
+        let sig = sk.sign(&msg);
+        let std_sig: Signature = std_sk.sign(&msg);
+        assert_eq!(std_sig.to_bytes().as_slice(), sig);
+
+        std_pk
+            .verify(&msg, &Signature::from_slice(&sig).unwrap())
+            .unwrap();
+        P256::verify(&pk, &msg, &std_sig.to_bytes().into()).unwrap();
+    }
+}
+
+#[test]
+fn test_std_p256_scalar() {
+    let mut a = [0u8; 32];
+    let mut b = [0u8; 32];
+    let mut rng = FastRng::new_from_seed(&[0u8; 32]);
+    for _ in 0..1000 {
+        rng.fill_bytes(&mut a);
+        rng.fill_bytes(&mut b);
+
+        let (std_a, std_b) = (
+            <Scalar as Reduce<U256>>::reduce_bytes(GenericArray::from_slice(&a)),
+            <Scalar as Reduce<U256>>::reduce_bytes(GenericArray::from_slice(&b)),
+        );
+        let (sa, sb) = (
+            Scalar256::from_bytes_reduce(&a),
+            Scalar256::from_bytes_reduce(&b),
+        );
+
+        assert_eq!(
+            Scalar256::from_bytes(&a).is_some(),
+            bool::from(Scalar::from_repr(a.into()).is_some())
+        );
+        assert_eq!(sa.to_bytes().as_slice(), std_a.to_bytes().as_slice());
+        assert_eq!(
+            sa.add(&sb).to_bytes().as_slice(),
+            (std_a + std_b).to_bytes().as_slice()
+        );
+        assert_eq!(
+            sa.sub(&sb).to_bytes().as_slice(),
+            (std_a - std_b).to_bytes().as_slice()
+        );
+        assert_eq!(
+            sa.mult(&sb).to_bytes().as_slice(),
+            (std_a * std_b).to_bytes().as_slice()
+        );
+        assert_eq!(
+            sa.inverse().to_bytes().as_slice(),
+            std_a.invert().unwrap().to_bytes().as_slice()
+        );
+    }
+}
+
+#[test]
+fn test_p256_compressed() {
+    let pk = decode_fix::<65>(b"0460fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb67903fe1008b8bc99a41ae9e95628bc64f2f1b20c2d7e9f5177a3c294d4462299").unwrap();
+    let cpk =
+        decode_fix::<33>(b"0360fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb6")
+            .unwrap();
+
+    assert_eq!(compress(&pk).unwrap(), cpk);
+    assert_eq!(decompress(&cpk).unwrap(), pk);
+
+    let mut bad = cpk;
+    bad[0] = 0x04;
+    assert!(decompress(&bad).is_err());
+
+    // x = 1 is not on the curve
+    let mut bad = [0u8; 33];
+    bad[0] = 0x02;
+    bad[32] = 0x01;
+    assert!(decompress(&bad).is_err());
+
+    let mut x = [0u8; 32];
+    let mut y = [0u8; 32];
+    let mut rng = FastRng::new_from_seed(&[0u8; 32]);
+    for _ in 0..20 {
+        rng.fill_bytes(&mut x);
+        rng.fill_bytes(&mut y);
+
+        let (sx, sy) = (P256::new(&x), P256::new(&y));
+        let std_pk =
+            VerifyingKey::from(&SigningKey::from_bytes(GenericArray::from_slice(&x)).unwrap());
+
+        let cpk = sx.to_compressed_public();
+        assert_eq!(std_pk.to_encoded_point(true).as_bytes(), cpk);
+        assert_eq!(compress(&sx.to_public()).unwrap(), cpk);
+        assert_eq!(decompress(&cpk).unwrap(), sx.to_public());
+
+        let shared = sy.exchange(&sx.to_public()).unwrap();
+        assert_eq!(sy.exchange_sec1(&cpk).unwrap(), shared);
+        assert_eq!(sy.exchange_sec1(&sx.to_public()).unwrap(), shared);
+        assert_eq!(
+            sx.exchange_sec1(&sy.to_compressed_public()).unwrap(),
+            shared
+        );
+        assert!(sy.exchange_sec1(&cpk[1..]).is_err());
+    }
+}
@@ -0,0 +1,69 @@
+use primit::{
+    error::KdfError,
+    hash::{sha1::SHA1, sha256::SHA256},
+    kdf::pbkdf2::pbkdf2,
+    utils::hex::decode_fix,
+};
+
+#[test]
+fn test_pbkdf2_sha1() {
+    // RFC6070 2, the 16777216 iterations case is skipped
+    let mut dk = [0u8; 20];
+    pbkdf2::<SHA1>(b"password", b"salt", 1, &mut dk).unwrap();
+    assert_eq!(
+        dk,
+        decode_fix::<20>(b"0c60c80f961f0e71f3a9b524af6012062fe037a6").unwrap()
+    );
+
+    pbkdf2::<SHA1>(b"password", b"salt", 2, &mut dk).unwrap();
+    assert_eq!(
+        dk,
+        decode_fix::<20>(b"ea6c014dc72d6f8ccd1ed92ace1d41f0d8de8957").unwrap()
+    );
+
+    pbkdf2::<SHA1>(b"password", b"salt", 4096, &mut dk).unwrap();
+    assert_eq!(
+        dk,
+        decode_fix::<20>(b"4b007901b765489abead49d926f721d065a429c1").unwrap()
+    );
+
+    let mut dk = [0u8; 25];
+    pbkdf2::<SHA1>(
+        b"passwordPASSWORDpassword",
+        b"saltSALTsaltSALTsaltSALTsaltSALTsalt",
+        4096,
+        &mut dk,
+    )
+    .unwrap();
+    assert_eq!(
+        dk,
+        decode_fix::<25>(b"3d2eec4fe41c849b80c8d83662c0e44a8b291a964cf2f07038").unwrap()
+    );
+
+    let mut dk = [0u8; 16];
+    pbkdf2::<SHA1>(b"pass\0word", b"sa\0lt", 4096, &mut dk).unwrap();
+    assert_eq!(
+        dk,
+        decode_fix::<16>(b"56fa6aa75548099dcc37d7f03425e0c3").unwrap()
+    );
+}
+
+#[test]
+fn test_pbkdf2_sha256() {
+    // RFC7914 11
+    let mut dk = [0u8; 64];
+    pbkdf2::<SHA256>(b"passwd", b"salt", 1, &mut dk).unwrap();
+    assert_eq!(dk, decode_fix::<64>(b"55ac046e56e3089fec1691c22544b605f94185216dde0465e68b9d57c20dacbc49ca9cccf179b645991664b39d77ef317c71b845b1e30bd509112041d3a19783").unwrap());
+
+    pbkdf2::<SHA256>(b"Password", b"NaCl", 80000, &mut dk).unwrap();
+    assert_eq!(dk, decode_fix::<64>(b"4ddcd8f60b98be21830cee5ef22701f9641a4418d04c0414aeff08876b34ab56a1d425a1225833549adb841b51c9b3176a272bdebba1d078478f62b397f33c8d").unwrap());
+}
+
+#[test]
+fn test_pbkdf2_invalid() {
+    let mut dk = [0u8; 32];
+    assert_eq!(
+        pbkdf2::<SHA256>(b"password", b"salt", 0, &mut dk),
+        Err(KdfError::InvalidIterationCount)
+    );
+}
@@ -0,0 +1,45 @@
+use polyval::universal_hash::{KeyInit, UniversalHash};
+use primit::{
+    mac::{
+        polyval::{polyval, Polyval},
+        Mac,
+    },
+    rng::{FastRng, Rng},
+    utils::hex::decode_fix,
+};
+
+// RFC8452 Appendix A
+#[test]
+fn test_polyval() {
+    let key = decode_fix::<16>(b"25629347589242761d31f826ba4b757b").unwrap();
+    let message =
+        decode_fix::<32>(b"4f4f95668c83dfb6401762bb2d01a262d1a24ddd2721d006bbe45f20d3c9f362")
+            .unwrap();
+    let expected = decode_fix::<16>(b"f7a3b47b846119fae5b7866cf5e5b77e").unwrap();
+
+    assert_eq!(polyval(&key, &message), expected);
+
+    let mut mac = Polyval::new(&key);
+    mac.update(message[..16].try_into().unwrap());
+    assert_eq!(mac.finalize(&message[16..]), expected);
+}
+
+#[test]
+fn test_polyval_fuzz() {
+    let mut rng = FastRng::new_from_seed(&[0u8; 32]);
+    let mut key = [0u8; 16];
+    let mut message = [0u8; 256];
+
+    for length in 0..256 {
+        rng.fill_bytes(&mut key);
+        rng.fill_bytes(&mut message);
+
+        let mut std_mac = polyval::Polyval::new_from_slice(&key).unwrap();
+        std_mac.update_padded(&message[..length]);
+
+        assert_eq!(
+            polyval(&key, &message[..length]),
+            std_mac.finalize().as_slice()
+        );
+    }
+}
@@ -0,0 +1,68 @@
+use md5::Digest;
+use primit::{
+    error::HashError,
+    hash::{
+        sha1::{sha1, sha1_checked, SHA1CD},
+        Digest as _,
+    },
+    rng::{FastRng, Rng},
+    utils::hex::decode_fix,
+};
+use sha1::Sha1;
+
+// the first 320 bytes of shattered-1.pdf and shattered-2.pdf, https://shattered.io
+const SHATTERED_1: &[u8; 640] = b"255044462d312e330a25e2e3cfd30a0a0a312030206f626a0a3c3c2f57696474682032203020522f4865696768742033203020522f547970652034203020522f537562747970652035203020522f46696c7465722036203020522f436f6c6f7253706163652037203020522f4c656e6774682038203020522f42697473506572436f6d706f6e656e7420383e3e0a73747265616d0affd8fffe00245348412d3120697320646561642121212121852fec092339759c39b1a1c63c4c97e1fffe017346dc9166b67e118f029ab621b2560ff9ca67cca8c7f85ba84c79030c2b3de218f86db3a90901d5df45c14f26fedfb3dc38e96ac22fe7bd728f0e45bce046d23c570feb141398bb552ef5a0a82be331fea48037b8b5d71f0e332edf93ac3500eb4ddc0decc1a864790c782c76215660dd309791d06bd0af3f98cda4bc4629b1";
+const SHATTERED_2: &[u8; 640] = b"255044462d312e330a25e2e3cfd30a0a0a312030206f626a0a3c3c2f57696474682032203020522f4865696768742033203020522f547970652034203020522f537562747970652035203020522f46696c7465722036203020522f436f6c6f7253706163652037203020522f4c656e6774682038203020522f42697473506572436f6d706f6e656e7420383e3e0a73747265616d0affd8fffe00245348412d3120697320646561642121212121852fec092339759c39b1a1c63c4c97e1fffe017f46dc93a6b67e013b029aaa1db2560b45ca67d688c7f84b8c4c791fe02b3df614f86db1690901c56b45c1530afedfb76038e972722fe7ad728f0e4904e046c230570fe9d41398abe12ef5bc942be33542a4802d98b5d70f2a332ec37fac3514e74ddc0f2cc1a874cd0c78305a21566461309789606bd0bf3f98cda8044629a1";
+
+#[test]
+fn test_sha1() {
+    // RFC3174 7.3
+    assert_eq!(
+        sha1(b"abc"),
+        decode_fix::<20>(b"a9993e364706816aba3e25717850c26c9cd0d89d").unwrap()
+    );
+    assert_eq!(
+        sha1(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"),
+        decode_fix::<20>(b"84983e441c3bd26ebaae4aa1f95129e5e54670f1").unwrap()
+    );
+}
+
+#[test]
+fn test_sha1_fuzz() {
+    let mut input = [0u8; 512];
+    let mut rng = FastRng::new_from_seed(&[0u8; 32]);
+
+    for l in 0..input.len() {
+        rng.fill_bytes(&mut input[..l]);
+
+        let mut std_digest = Sha1::new();
+        std_digest.update(&input[..l]);
+        let output = std_digest.finalize();
+
+        assert_eq!(&sha1(&input[..l]), output.as_slice());
+        assert_eq!(&sha1_checked(&input[..l]).unwrap(), output.as_slice());
+    }
+}
+
+#[test]
+fn test_sha1_collision_detection() {
+    let m1 = decode_fix::<320>(SHATTERED_1).unwrap();
+    let m2 = decode_fix::<320>(SHATTERED_2).unwrap();
+
+    // a real collision
+    assert_ne!(m1, m2);
+    assert_eq!(sha1(&m1), sha1(&m2));
+
+    assert_eq!(sha1_checked(&m1), Err(HashError::CollisionDetected));
+    assert_eq!(sha1_checked(&m2), Err(HashError::CollisionDetected));
+
+    // safe hash, compatible with Git
+    assert_eq!(
+        SHA1CD::new().digest(&m1),
+        decode_fix::<20>(b"7117b3cb9225aaf0d8ef1a40e493957b0bf8693d").unwrap()
+    );
+    assert_eq!(
+        SHA1CD::new().digest(&m2),
+        decode_fix::<20>(b"29f38ae9fd98e2931120fa0bf213e024250d3f6a").unwrap()
+    );
+}
@@ -0,0 +1,32 @@
+use md5::Digest;
+use primit::{
+    hash::sha384::sha384,
+    rng::{FastRng, Rng},
+    utils::hex::decode_fix,
+};
+use sha2::Sha384;
+
+#[test]
+fn test_sha384() {
+    // FIPS 180-2 D.1
+    assert_eq!(
+        sha384(b"abc"),
+        decode_fix::<48>(b"cb00753f45a35e8bb5a03d699ac65007272c32ab0eded1631a8b605a43ff5bed8086072ba1e7cc2358baeca134c825a7").unwrap()
+    );
+}
+
+#[test]
+fn test_sha384_fuzz() {
+    let mut input = [0u8; 512];
+    let mut rng = FastRng::new_from_seed(&[0u8; 32]);
+
+    for l in 0..input.len() {
+        rng.fill_bytes(&mut input[..l]);
+
+        let mut std_digest = Sha384::new();
+        std_digest.update(&input[..l]);
+        let output = std_digest.finalize();
+
+        assert_eq!(&sha384(&input[..l]), output.as_slice());
+    }
+}
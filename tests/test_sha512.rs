@@ -0,0 +1,32 @@
+use md5::Digest;
+use primit::{
+    hash::sha512::sha512,
+    rng::{FastRng, Rng},
+    utils::hex::decode_fix,
+};
+use sha2::Sha512;
+
+#[test]
+fn test_sha512() {
+    // FIPS 180-2 C.1
+    assert_eq!(
+        sha512(b"abc"),
+        decode_fix::<64>(b"ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f").unwrap()
+    );
+}
+
+#[test]
+fn test_sha512_fuzz() {
+    let mut input = [0u8; 512];
+    let mut rng = FastRng::new_from_seed(&[0u8; 32]);
+
+    for l in 0..input.len() {
+        rng.fill_bytes(&mut input[..l]);
+
+        let mut std_digest = Sha512::new();
+        std_digest.update(&input[..l]);
+        let output = std_digest.finalize();
+
+        assert_eq!(&sha512(&input[..l]), output.as_slice());
+    }
+}
@@ -0,0 +1,77 @@
+use primit::{
+    ec::{
+        x25519::{x25519, BASEPOINT, X25519},
+        ECDH,
+    },
+    utils::hex::decode_fix,
+};
+
+#[test]
+fn test_x25519() {
+    // RFC7748 5.2
+    let k = decode_fix::<32>(b"a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4")
+        .unwrap();
+    let u = decode_fix::<32>(b"e6db6867583030db3594c1a424b15f7c726624ec26b3353b10a903a6d0ab1c4c")
+        .unwrap();
+    let r = decode_fix::<32>(b"c3da55379de9c6908e94ea4df28d084f32eccf03491c71f754b4075577a28552")
+        .unwrap();
+    assert_eq!(x25519(&k, &u), r);
+
+    let k = decode_fix::<32>(b"4b66e9d4d1b4673c5ad22691957d6af5c11b6421e0ea01d42ca4169e7918ba0d")
+        .unwrap();
+    let u = decode_fix::<32>(b"e5210f12786811d3f4b7959d0538ae2c31dbe7106fc03c3efc4cd549c715a493")
+        .unwrap();
+    let r = decode_fix::<32>(b"95cbde9476e8907d7aade45cb4b873f88b595a68799fa152e6f8f7647aac7957")
+        .unwrap();
+    assert_eq!(x25519(&k, &u), r);
+}
+
+#[test]
+fn test_x25519_iteration() {
+    // RFC7748 5.2
+    let mut k = BASEPOINT;
+    let mut u = BASEPOINT;
+    for i in 0..1000 {
+        (k, u) = (x25519(&k, &u), k);
+
+        if i == 0 {
+            assert_eq!(
+                k,
+                decode_fix::<32>(
+                    b"422c8e7a6227d7bca1350b3e2bb7279f7897b87bb6854b783c60e80311ae3079"
+                )
+                .unwrap()
+            );
+        }
+    }
+    assert_eq!(
+        k,
+        decode_fix::<32>(b"684cf59ba83309552800ef566f2f4d3c1c3887c49360e3875f2eb94d99532c51")
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_x25519_exchange() {
+    // RFC7748 6.1
+    let a = decode_fix::<32>(b"77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a")
+        .unwrap();
+    let pa = decode_fix::<32>(b"8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a")
+        .unwrap();
+    let b = decode_fix::<32>(b"5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb")
+        .unwrap();
+    let pb = decode_fix::<32>(b"de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f")
+        .unwrap();
+    let shared =
+        decode_fix::<32>(b"4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742")
+            .unwrap();
+
+    let (a, b) = (X25519::new(&a), X25519::new(&b));
+    assert_eq!(a.to_public(), pa);
+    assert_eq!(b.to_public(), pb);
+    assert_eq!(a.exchange(&pb).unwrap(), shared);
+    assert_eq!(b.exchange(&pa).unwrap(), shared);
+
+    // low order point
+    assert!(a.exchange(&[0u8; 32]).is_err());
+}